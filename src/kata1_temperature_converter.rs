@@ -3,10 +3,84 @@
 // Focus: Good Naming, Single Responsibility, Type Safety
 // ============================================
 
+use std::error::Error;
 use std::fmt;
 
+pub mod batch;
+pub mod comfort;
+pub mod comparison;
+pub mod conversion_table;
+pub mod exact;
+pub mod formatting;
+pub mod interval;
+pub mod parse;
+pub mod quantity;
+pub mod scale_registry;
+pub mod sensors;
+pub mod series;
+pub mod typed;
+
+/// Temperature of water's ice point in Kelvin as an exact fraction, the pivot shared by every scale
+const ICE_POINT_KELVIN_FRACTION: (i128, i128) = (27315, 100);
+
+const ICE_POINT_KELVIN: f64 =
+    ICE_POINT_KELVIN_FRACTION.0 as f64 / ICE_POINT_KELVIN_FRACTION.1 as f64;
+
+/// Slack for rounding when a reading sits exactly at absolute zero on another scale
+const ABSOLUTE_ZERO_TOLERANCE: f64 = 1e-9;
+
+#[derive(Debug, PartialEq)]
+pub enum TemperatureError {
+    BelowAbsoluteZero(TemperatureUnit),
+    NotFinite,
+}
+
+impl fmt::Display for TemperatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemperatureError::BelowAbsoluteZero(temperature) => {
+                write!(f, "Temperature {} is below absolute zero", temperature)
+            }
+            TemperatureError::NotFinite => write!(f, "Temperature must be a finite number"),
+        }
+    }
+}
+
+impl Error for TemperatureError {}
+
+/// A temperature scale defined by its affine mapping to Kelvin:
+/// `kelvin = (value - ice_point) * numerator / denominator + 273.15`
+pub trait Scale {
+    fn name(&self) -> &str;
+    fn symbol(&self) -> &str;
+
+    /// Reading on this scale at the ice point of water (273.15 K)
+    fn ice_point(&self) -> f64;
+
+    /// Size of one degree of this scale in kelvins, as `(numerator, denominator)`
+    fn degree_in_kelvin(&self) -> (f64, f64);
+
+    /// Convert a reading on this scale to any other scale
+    fn convert_value(&self, value: f64, target: &dyn Scale) -> f64 {
+        let (source_numerator, source_denominator) = self.degree_in_kelvin();
+        let (target_numerator, target_denominator) = target.degree_in_kelvin();
+        (value - self.ice_point()) * source_numerator * target_denominator
+            / (source_denominator * target_numerator)
+            + target.ice_point()
+    }
+
+    /// Convert a difference between two readings on this scale to any other scale
+    fn convert_interval(&self, delta: f64, target: &dyn Scale) -> f64 {
+        let (source_numerator, source_denominator) = self.degree_in_kelvin();
+        let (target_numerator, target_denominator) = target.degree_in_kelvin();
+        delta * source_numerator * target_denominator / (source_denominator * target_numerator)
+    }
+}
+
 /// Strongly-typed representation of temperature units
-#[derive(Debug, Clone, Copy, PartialEq)]
+///
+/// Equality and ordering are physical: `Celsius(0.0) == Kelvin(273.15)`.
+#[derive(Debug, Clone, Copy)]
 pub enum TemperatureUnit {
     Celsius(f64),
     Fahrenheit(f64),
@@ -14,40 +88,97 @@ pub enum TemperatureUnit {
 }
 
 impl TemperatureUnit {
-    /// Convert to Celsius
-    pub fn to_celsius(&self) -> f64 {
-        match self {
-            TemperatureUnit::Celsius(c) => *c,
-            TemperatureUnit::Fahrenheit(f) => (f - 32.0) * 5.0 / 9.0,
-            TemperatureUnit::Kelvin(k) => k - 273.15,
+    /// Create a temperature on the given scale
+    pub fn new(scale: TemperatureScale, value: f64) -> Self {
+        match scale {
+            TemperatureScale::Celsius => TemperatureUnit::Celsius(value),
+            TemperatureScale::Fahrenheit => TemperatureUnit::Fahrenheit(value),
+            TemperatureScale::Kelvin => TemperatureUnit::Kelvin(value),
         }
     }
 
-    /// Convert to Fahrenheit
-    pub fn to_fahrenheit(&self) -> f64 {
+    /// Scale the temperature is expressed in
+    pub fn scale(self) -> TemperatureScale {
         match self {
-            TemperatureUnit::Celsius(c) => c * 9.0 / 5.0 + 32.0,
-            TemperatureUnit::Fahrenheit(f) => *f,
-            TemperatureUnit::Kelvin(k) => (k - 273.15) * 9.0 / 5.0 + 32.0,
+            TemperatureUnit::Celsius(_) => TemperatureScale::Celsius,
+            TemperatureUnit::Fahrenheit(_) => TemperatureScale::Fahrenheit,
+            TemperatureUnit::Kelvin(_) => TemperatureScale::Kelvin,
         }
     }
 
-    /// Convert to Kelvin
-    pub fn to_kelvin(&self) -> f64 {
+    /// Numeric reading on its own scale
+    pub fn value(self) -> f64 {
         match self {
-            TemperatureUnit::Celsius(c) => c + 273.15,
-            TemperatureUnit::Fahrenheit(f) => (f - 32.0) * 5.0 / 9.0 + 273.15,
-            TemperatureUnit::Kelvin(k) => *k,
+            TemperatureUnit::Celsius(value)
+            | TemperatureUnit::Fahrenheit(value)
+            | TemperatureUnit::Kelvin(value) => value,
         }
     }
 
+    /// Convert to Celsius
+    pub fn to_celsius(self) -> f64 {
+        self.to_scale(&TemperatureScale::Celsius)
+    }
+
+    /// Convert to Fahrenheit
+    pub fn to_fahrenheit(self) -> f64 {
+        self.to_scale(&TemperatureScale::Fahrenheit)
+    }
+
+    /// Convert to Kelvin
+    pub fn to_kelvin(self) -> f64 {
+        self.to_scale(&TemperatureScale::Kelvin)
+    }
+
+    /// Convert to the reading on any scale, including ones outside `TemperatureScale`
+    pub fn to_scale(self, target: &dyn Scale) -> f64 {
+        self.scale().convert_value(self.value(), target)
+    }
+
     /// Convert to a specified unit
-    pub fn convert_to(&self, target: TemperatureScale) -> TemperatureUnit {
-        match target {
-            TemperatureScale::Celsius => TemperatureUnit::Celsius(self.to_celsius()),
-            TemperatureScale::Fahrenheit => TemperatureUnit::Fahrenheit(self.to_fahrenheit()),
-            TemperatureScale::Kelvin => TemperatureUnit::Kelvin(self.to_kelvin()),
+    pub fn convert_to(self, target: TemperatureScale) -> TemperatureUnit {
+        TemperatureUnit::new(target, self.to_scale(&target))
+    }
+
+    /// Create a temperature, rejecting NaN, infinity and readings below absolute zero
+    pub fn try_new(scale: TemperatureScale, value: f64) -> Result<Self, TemperatureError> {
+        TemperatureUnit::new(scale, value).validate()
+    }
+
+    /// Create a validated Celsius temperature
+    pub fn try_celsius(value: f64) -> Result<Self, TemperatureError> {
+        Self::try_new(TemperatureScale::Celsius, value)
+    }
+
+    /// Create a validated Fahrenheit temperature
+    pub fn try_fahrenheit(value: f64) -> Result<Self, TemperatureError> {
+        Self::try_new(TemperatureScale::Fahrenheit, value)
+    }
+
+    /// Create a validated Kelvin temperature
+    pub fn try_kelvin(value: f64) -> Result<Self, TemperatureError> {
+        Self::try_new(TemperatureScale::Kelvin, value)
+    }
+
+    /// Check that the temperature is physically possible
+    pub fn validate(self) -> Result<Self, TemperatureError> {
+        if !self.value().is_finite() {
+            return Err(TemperatureError::NotFinite);
         }
+
+        if self.to_kelvin() < -ABSOLUTE_ZERO_TOLERANCE {
+            return Err(TemperatureError::BelowAbsoluteZero(self));
+        }
+
+        Ok(self)
+    }
+
+    /// Convert to a specified unit, validating both the source and the result
+    pub fn checked_convert_to(
+        self,
+        target: TemperatureScale,
+    ) -> Result<TemperatureUnit, TemperatureError> {
+        self.validate()?.convert_to(target).validate()
     }
 }
 
@@ -59,16 +190,69 @@ pub enum TemperatureScale {
     Kelvin,
 }
 
-impl fmt::Display for TemperatureUnit {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+/// Exact definition of a built-in scale, shared by the `f64`, rational and quantity conversions
+struct ScaleDefinition {
+    name: &'static str,
+    symbol: &'static str,
+    /// Reading at the ice point of water, as `(numerator, denominator)`
+    ice_point: (i128, i128),
+    /// Size of one degree in kelvins, as `(numerator, denominator)`
+    degree_in_kelvin: (i128, i128),
+}
+
+impl TemperatureScale {
+    const fn definition(self) -> ScaleDefinition {
         match self {
-            TemperatureUnit::Celsius(temp) => write!(f, "{:.2}°C", temp),
-            TemperatureUnit::Fahrenheit(temp) => write!(f, "{:.2}°F", temp),
-            TemperatureUnit::Kelvin(temp) => write!(f, "{:.2}K", temp),
+            TemperatureScale::Celsius => ScaleDefinition {
+                name: "Celsius",
+                symbol: "°C",
+                ice_point: (0, 1),
+                degree_in_kelvin: (1, 1),
+            },
+            TemperatureScale::Fahrenheit => ScaleDefinition {
+                name: "Fahrenheit",
+                symbol: "°F",
+                ice_point: (32, 1),
+                degree_in_kelvin: (5, 9),
+            },
+            TemperatureScale::Kelvin => ScaleDefinition {
+                name: "Kelvin",
+                symbol: "K",
+                ice_point: ICE_POINT_KELVIN_FRACTION,
+                degree_in_kelvin: (1, 1),
+            },
         }
     }
 }
 
+impl Scale for TemperatureScale {
+    fn name(&self) -> &str {
+        self.definition().name
+    }
+
+    fn symbol(&self) -> &str {
+        self.definition().symbol
+    }
+
+    fn ice_point(&self) -> f64 {
+        let (numerator, denominator) = self.definition().ice_point;
+        numerator as f64 / denominator as f64
+    }
+
+    fn degree_in_kelvin(&self) -> (f64, f64) {
+        let (numerator, denominator) = self.definition().degree_in_kelvin;
+        (numerator as f64, denominator as f64)
+    }
+}
+
+impl fmt::Display for TemperatureUnit {
+    /// Two decimals by default; `{:.N}` selects another precision
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(2);
+        write!(f, "{:.*}{}", precision, self.value(), self.scale().symbol())
+    }
+}
+
 #[cfg(test)]
 mod temperature_tests {
     use super::*;
@@ -93,4 +277,31 @@ mod temperature_tests {
         let kelvin = TemperatureUnit::Kelvin(273.15);
         assert!((kelvin.to_celsius() - 0.0).abs() < 0.001);
     }
+
+    #[test]
+    fn test_convert_to_and_display() {
+        let boiling = TemperatureUnit::Celsius(100.0);
+        assert_eq!(boiling.convert_to(TemperatureScale::Fahrenheit), TemperatureUnit::Fahrenheit(212.0));
+        assert_eq!(boiling.convert_to(TemperatureScale::Kelvin).to_string(), "373.15K");
+        assert_eq!(format!("{:.1}", TemperatureUnit::Fahrenheit(98.66)), "98.7°F");
+    }
+
+    #[test]
+    fn test_rejects_impossible_temperatures() {
+        assert_eq!(
+            TemperatureUnit::try_kelvin(-5.0),
+            Err(TemperatureError::BelowAbsoluteZero(TemperatureUnit::Kelvin(-5.0)))
+        );
+        assert!(TemperatureUnit::try_celsius(-300.0).is_err());
+        assert_eq!(TemperatureUnit::try_celsius(f64::NAN), Err(TemperatureError::NotFinite));
+        assert_eq!(TemperatureUnit::try_fahrenheit(f64::INFINITY), Err(TemperatureError::NotFinite));
+        assert!(TemperatureUnit::try_fahrenheit(-459.67).is_ok());
+    }
+
+    #[test]
+    fn test_checked_convert_to() {
+        let converted = TemperatureUnit::Celsius(-273.15).checked_convert_to(TemperatureScale::Kelvin);
+        assert_eq!(converted, Ok(TemperatureUnit::Kelvin(0.0)));
+        assert!(TemperatureUnit::Kelvin(-1.0).checked_convert_to(TemperatureScale::Celsius).is_err());
+    }
 }
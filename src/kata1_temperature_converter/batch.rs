@@ -0,0 +1,221 @@
+// ============================================
+// Kata 1 extension: Batch Conversion
+// Streaming delimited rows through the converter, one bad row at a time
+// ============================================
+
+use super::parse::ParseTemperatureError;
+use super::{TemperatureError, TemperatureScale, TemperatureUnit};
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+#[derive(Debug, PartialEq)]
+pub enum RowErrorKind {
+    InvalidUtf8,
+    MissingColumn(usize),
+    Parse(ParseTemperatureError),
+    Temperature(TemperatureError),
+}
+
+/// A row that could not be converted, identified by its 1-based line number
+#[derive(Debug, PartialEq)]
+pub struct RowError {
+    pub line: usize,
+    pub kind: RowErrorKind,
+}
+
+impl fmt::Display for RowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            RowErrorKind::InvalidUtf8 => write!(f, "Line {}: not valid UTF-8", self.line),
+            RowErrorKind::MissingColumn(column) => {
+                write!(f, "Line {}: no column {}", self.line, column)
+            }
+            RowErrorKind::Parse(error) => write!(f, "Line {}: {}", self.line, error),
+            RowErrorKind::Temperature(error) => write!(f, "Line {}: {}", self.line, error),
+        }
+    }
+}
+
+impl Error for RowError {}
+
+/// Outcome of a batch run; failed rows are left out of the output
+#[derive(Debug, Default, PartialEq)]
+pub struct BatchReport {
+    pub converted: usize,
+    pub errors: Vec<RowError>,
+}
+
+/// Rewrites one temperature column of delimited text into a target scale.
+/// Fields are split on the delimiter as-is; quoted fields are not supported.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BatchConverter {
+    column: usize,
+    delimiter: char,
+    has_header: bool,
+    precision: usize,
+    target: TemperatureScale,
+}
+
+impl BatchConverter {
+    /// Converts the first comma-separated column with a header row, to two decimals
+    pub fn new(target: TemperatureScale) -> Self {
+        BatchConverter {
+            column: 0,
+            delimiter: ',',
+            has_header: true,
+            precision: 2,
+            target,
+        }
+    }
+
+    /// Zero-based index of the temperature field
+    pub fn column(mut self, column: usize) -> Self {
+        self.column = column;
+        self
+    }
+
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    pub fn has_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Stream every row, collecting row errors into the report
+    pub fn convert<R: BufRead, W: Write>(&self, reader: R, writer: W) -> io::Result<BatchReport> {
+        let mut errors = Vec::new();
+        let converted = self.convert_with(reader, writer, |error| errors.push(error))?;
+        Ok(BatchReport { converted, errors })
+    }
+
+    /// Stream every row, handing row errors to `on_error` instead of buffering them.
+    /// Returns the number of converted rows; only I/O failures abort the batch.
+    pub fn convert_with<R: BufRead, W: Write>(
+        &self,
+        mut reader: R,
+        mut writer: W,
+        mut on_error: impl FnMut(RowError),
+    ) -> io::Result<usize> {
+        let mut line = Vec::new();
+        let mut line_number = 0;
+        let mut converted = 0;
+
+        loop {
+            line.clear();
+            if reader.read_until(b'\n', &mut line)? == 0 {
+                break;
+            }
+            line_number += 1;
+            let end = line.iter().rposition(|byte| !matches!(byte, b'\r' | b'\n'));
+            let row = &line[..end.map_or(0, |index| index + 1)];
+
+            if line_number == 1 && self.has_header {
+                writer.write_all(row)?;
+                writeln!(writer)?;
+                continue;
+            }
+            let decoded = std::str::from_utf8(row).map_err(|_| RowErrorKind::InvalidUtf8);
+            if decoded.as_ref().is_ok_and(|row| row.trim().is_empty()) {
+                continue;
+            }
+
+            match decoded.and_then(|row| self.convert_row(row)) {
+                Ok(output) => {
+                    writeln!(writer, "{}", output)?;
+                    converted += 1;
+                }
+                Err(kind) => on_error(RowError { line: line_number, kind }),
+            }
+        }
+
+        writer.flush()?;
+        Ok(converted)
+    }
+
+    fn convert_row(&self, row: &str) -> Result<String, RowErrorKind> {
+        let mut fields: Vec<&str> = row.split(self.delimiter).collect();
+        let field = fields
+            .get(self.column)
+            .ok_or(RowErrorKind::MissingColumn(self.column))?;
+
+        let temperature = field
+            .parse::<TemperatureUnit>()
+            .map_err(RowErrorKind::Parse)?
+            .checked_convert_to(self.target)
+            .map_err(RowErrorKind::Temperature)?;
+
+        let formatted = format!("{:.*}", self.precision, temperature);
+        fields[self.column] = &formatted;
+        Ok(fields.join(&self.delimiter.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod batch_tests {
+    use super::*;
+
+    fn run(converter: BatchConverter, input: &str) -> (String, BatchReport) {
+        let mut output = Vec::new();
+        let report = converter.convert(input.as_bytes(), &mut output).unwrap();
+        (String::from_utf8(output).unwrap(), report)
+    }
+
+    #[test]
+    fn test_converts_mixed_units() {
+        let input = "sensor,reading\nA,100°C\r\nB,32 F\nC,273.15 K\n";
+        let (output, report) = run(BatchConverter::new(TemperatureScale::Celsius).column(1), input);
+        assert_eq!(output, "sensor,reading\nA,100.00°C\nB,0.00°C\nC,0.00°C\n");
+        assert_eq!(report, BatchReport { converted: 3, errors: Vec::new() });
+    }
+
+    #[test]
+    fn test_bad_rows_are_reported_not_fatal() {
+        let input = "warm;20C\nbroken\nhot;abc F\ncold;-500C\n\nfine;0 K\n";
+        let converter = BatchConverter::new(TemperatureScale::Kelvin)
+            .delimiter(';')
+            .column(1)
+            .has_header(false)
+            .precision(1);
+        let (output, report) = run(converter, input);
+
+        assert_eq!(output, "warm;293.1K\nfine;0.0K\n");
+        assert_eq!(report.converted, 2);
+        let lines: Vec<usize> = report.errors.iter().map(|error| error.line).collect();
+        assert_eq!(lines, [2, 3, 4]);
+        assert_eq!(report.errors[0].kind, RowErrorKind::MissingColumn(1));
+        assert!(matches!(report.errors[1].kind, RowErrorKind::Parse(_)));
+        assert!(matches!(report.errors[2].kind, RowErrorKind::Temperature(_)));
+    }
+
+    #[test]
+    fn test_errors_can_be_streamed() {
+        let input = "t\n1C\nx\n2C\n";
+        let mut failed = Vec::new();
+        let converted = BatchConverter::new(TemperatureScale::Fahrenheit)
+            .convert_with(input.as_bytes(), io::sink(), |error| failed.push(error.to_string()))
+            .unwrap();
+        assert_eq!(converted, 2);
+        assert_eq!(failed, ["Line 3: Invalid temperature value: 'x'"]);
+    }
+
+    #[test]
+    fn test_invalid_utf8_fails_only_its_row() {
+        let input = b"t\n1C\ncaf\xE9,2C\n3C\n";
+        let mut output = Vec::new();
+        let report = BatchConverter::new(TemperatureScale::Celsius)
+            .convert(&input[..], &mut output)
+            .unwrap();
+        assert_eq!(output, "t\n1.00°C\n3.00°C\n".as_bytes());
+        assert_eq!(report.converted, 2);
+        assert_eq!(report.errors, [RowError { line: 3, kind: RowErrorKind::InvalidUtf8 }]);
+    }
+}
@@ -0,0 +1,249 @@
+// ============================================
+// Kata 1 extension: Thermal Comfort Metrics
+// Heat index, wind chill and dew point from any temperature scale
+// ============================================
+
+use super::{TemperatureError, TemperatureScale, TemperatureUnit};
+use std::error::Error;
+use std::fmt;
+
+/// Rothfusz regression is only fitted for hot, humid air
+const HEAT_INDEX_MIN_FAHRENHEIT: f64 = 80.0;
+const HEAT_INDEX_MIN_HUMIDITY: f64 = 40.0;
+
+/// NWS wind chill is only defined for cold air and noticeable wind
+const WIND_CHILL_MAX_FAHRENHEIT: f64 = 50.0;
+const WIND_CHILL_MIN_MPH: f64 = 3.0;
+
+/// Magnus coefficients (Sonntag 1990), valid between -45°C and 60°C
+const MAGNUS_B: f64 = 17.62;
+const MAGNUS_C: f64 = 243.12;
+const DEW_POINT_MIN_CELSIUS: f64 = -45.0;
+const DEW_POINT_MAX_CELSIUS: f64 = 60.0;
+
+const KILOMETERS_PER_MILE: f64 = 1.609344;
+const SECONDS_PER_HOUR: f64 = 3600.0;
+
+/// Strongly-typed representation of wind speed units
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WindSpeed {
+    MetersPerSecond(f64),
+    KilometersPerHour(f64),
+    MilesPerHour(f64),
+}
+
+impl WindSpeed {
+    /// Convert to miles per hour
+    pub fn to_miles_per_hour(self) -> f64 {
+        match self {
+            WindSpeed::MetersPerSecond(speed) => {
+                speed * SECONDS_PER_HOUR / 1000.0 / KILOMETERS_PER_MILE
+            }
+            WindSpeed::KilometersPerHour(speed) => speed / KILOMETERS_PER_MILE,
+            WindSpeed::MilesPerHour(speed) => speed,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ComfortError {
+    InvalidTemperature(TemperatureError),
+    InvalidHumidity(f64),
+    InvalidWindSpeed(WindSpeed),
+    TemperatureOutOfRange(TemperatureUnit),
+    HumidityOutOfRange(f64),
+    WindSpeedOutOfRange(WindSpeed),
+}
+
+impl fmt::Display for ComfortError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ComfortError::InvalidTemperature(error) => write!(f, "{}", error),
+            ComfortError::InvalidHumidity(humidity) => {
+                write!(f, "Relative humidity must be between 0 and 100%, got {}", humidity)
+            }
+            ComfortError::InvalidWindSpeed(speed) => {
+                write!(f, "Wind speed must be a non-negative number, got {:?}", speed)
+            }
+            ComfortError::TemperatureOutOfRange(temperature) => {
+                write!(f, "Temperature {} is outside the formula's validity range", temperature)
+            }
+            ComfortError::HumidityOutOfRange(humidity) => {
+                write!(f, "Relative humidity {}% is outside the formula's validity range", humidity)
+            }
+            ComfortError::WindSpeedOutOfRange(speed) => {
+                write!(f, "Wind speed {:?} is outside the formula's validity range", speed)
+            }
+        }
+    }
+}
+
+impl Error for ComfortError {}
+
+/// Apparent temperature in hot, humid air (NWS Rothfusz regression)
+pub fn heat_index(
+    temperature: TemperatureUnit,
+    relative_humidity: f64,
+    output: TemperatureScale,
+) -> Result<TemperatureUnit, ComfortError> {
+    let temperature = validate_temperature(temperature)?;
+    let humidity = validate_humidity(relative_humidity)?;
+    let t = temperature.to_fahrenheit();
+
+    if t < HEAT_INDEX_MIN_FAHRENHEIT {
+        return Err(ComfortError::TemperatureOutOfRange(temperature));
+    }
+
+    if humidity < HEAT_INDEX_MIN_HUMIDITY {
+        return Err(ComfortError::HumidityOutOfRange(humidity));
+    }
+
+    let rh = humidity;
+    let index = -42.379 + 2.04901523 * t + 10.14333127 * rh
+        - 0.22475541 * t * rh
+        - 0.00683783 * t * t
+        - 0.05481717 * rh * rh
+        + 0.00122874 * t * t * rh
+        + 0.00085282 * t * rh * rh
+        - 0.00000199 * t * t * rh * rh;
+
+    Ok(TemperatureUnit::Fahrenheit(index).convert_to(output))
+}
+
+/// Apparent temperature in cold, windy air (NWS 2001 formula)
+pub fn wind_chill(
+    temperature: TemperatureUnit,
+    wind_speed: WindSpeed,
+    output: TemperatureScale,
+) -> Result<TemperatureUnit, ComfortError> {
+    let temperature = validate_temperature(temperature)?;
+    let mph = wind_speed.to_miles_per_hour();
+    if !mph.is_finite() || mph < 0.0 {
+        return Err(ComfortError::InvalidWindSpeed(wind_speed));
+    }
+
+    let t = temperature.to_fahrenheit();
+    if t > WIND_CHILL_MAX_FAHRENHEIT {
+        return Err(ComfortError::TemperatureOutOfRange(temperature));
+    }
+
+    if mph < WIND_CHILL_MIN_MPH {
+        return Err(ComfortError::WindSpeedOutOfRange(wind_speed));
+    }
+
+    let wind_factor = mph.powf(0.16);
+    let chill = 35.74 + 0.6215 * t - 35.75 * wind_factor + 0.4275 * t * wind_factor;
+
+    Ok(TemperatureUnit::Fahrenheit(chill).convert_to(output))
+}
+
+/// Temperature at which the air becomes saturated (Magnus formula)
+pub fn dew_point(
+    temperature: TemperatureUnit,
+    relative_humidity: f64,
+    output: TemperatureScale,
+) -> Result<TemperatureUnit, ComfortError> {
+    let temperature = validate_temperature(temperature)?;
+    let humidity = validate_humidity(relative_humidity)?;
+    if humidity == 0.0 {
+        return Err(ComfortError::HumidityOutOfRange(humidity));
+    }
+
+    let t = temperature.to_celsius();
+    if !(DEW_POINT_MIN_CELSIUS..=DEW_POINT_MAX_CELSIUS).contains(&t) {
+        return Err(ComfortError::TemperatureOutOfRange(temperature));
+    }
+
+    let gamma = (humidity / 100.0).ln() + MAGNUS_B * t / (MAGNUS_C + t);
+    let dew_point = MAGNUS_C * gamma / (MAGNUS_B - gamma);
+
+    Ok(TemperatureUnit::Celsius(dew_point).convert_to(output))
+}
+
+fn validate_temperature(temperature: TemperatureUnit) -> Result<TemperatureUnit, ComfortError> {
+    temperature.validate().map_err(ComfortError::InvalidTemperature)
+}
+
+fn validate_humidity(relative_humidity: f64) -> Result<f64, ComfortError> {
+    if (0.0..=100.0).contains(&relative_humidity) {
+        Ok(relative_humidity)
+    } else {
+        Err(ComfortError::InvalidHumidity(relative_humidity))
+    }
+}
+
+#[cfg(test)]
+mod comfort_tests {
+    use super::*;
+
+    #[test]
+    fn test_heat_index() {
+        let index = heat_index(TemperatureUnit::Fahrenheit(90.0), 70.0, TemperatureScale::Fahrenheit)
+            .unwrap();
+        assert!((index.value() - 106.0).abs() < 0.5);
+
+        let celsius = heat_index(TemperatureUnit::Celsius(32.22), 70.0, TemperatureScale::Celsius)
+            .unwrap();
+        assert!((celsius.to_fahrenheit() - 106.0).abs() < 0.5);
+
+        assert_eq!(
+            heat_index(TemperatureUnit::Celsius(20.0), 70.0, TemperatureScale::Celsius),
+            Err(ComfortError::TemperatureOutOfRange(TemperatureUnit::Celsius(20.0)))
+        );
+    }
+
+    #[test]
+    fn test_wind_chill() {
+        let chill = wind_chill(
+            TemperatureUnit::Fahrenheit(0.0),
+            WindSpeed::MilesPerHour(15.0),
+            TemperatureScale::Fahrenheit,
+        )
+        .unwrap();
+        assert!((chill.value() - -19.0).abs() < 0.5);
+
+        assert_eq!(
+            wind_chill(
+                TemperatureUnit::Celsius(-5.0),
+                WindSpeed::KilometersPerHour(2.0),
+                TemperatureScale::Celsius
+            ),
+            Err(ComfortError::WindSpeedOutOfRange(WindSpeed::KilometersPerHour(2.0)))
+        );
+    }
+
+    #[test]
+    fn test_dew_point() {
+        let dew = dew_point(TemperatureUnit::Kelvin(298.15), 60.0, TemperatureScale::Celsius).unwrap();
+        assert!((dew.value() - 16.7).abs() < 0.1);
+
+        let saturated = dew_point(TemperatureUnit::Celsius(10.0), 100.0, TemperatureScale::Celsius)
+            .unwrap();
+        assert!((saturated.value() - 10.0).abs() < 1e-9);
+
+        assert_eq!(
+            dew_point(TemperatureUnit::Celsius(10.0), 120.0, TemperatureScale::Celsius),
+            Err(ComfortError::InvalidHumidity(120.0))
+        );
+    }
+
+    #[test]
+    fn test_invalid_temperatures_are_rejected() {
+        let below_zero = TemperatureUnit::Kelvin(-10.0);
+        assert_eq!(
+            dew_point(below_zero, 50.0, TemperatureScale::Celsius),
+            Err(ComfortError::InvalidTemperature(TemperatureError::BelowAbsoluteZero(below_zero)))
+        );
+        assert_eq!(
+            heat_index(TemperatureUnit::Fahrenheit(f64::NAN), 70.0, TemperatureScale::Celsius),
+            Err(ComfortError::InvalidTemperature(TemperatureError::NotFinite))
+        );
+        let breeze = WindSpeed::MilesPerHour(10.0);
+        assert_eq!(
+            wind_chill(TemperatureUnit::Celsius(-300.0), breeze, TemperatureScale::Celsius),
+            Err(ComfortError::InvalidTemperature(TemperatureError::BelowAbsoluteZero(
+                TemperatureUnit::Celsius(-300.0)
+            )))
+        );
+    }
+}
@@ -0,0 +1,125 @@
+// ============================================
+// Kata 1 extension: Comparing Temperatures
+// Physically meaningful equality, ordering and aggregation across scales
+// ============================================
+
+use super::interval::TemperatureInterval;
+use super::{TemperatureScale, TemperatureUnit};
+use std::cmp::Ordering;
+
+impl PartialEq for TemperatureUnit {
+    fn eq(&self, other: &TemperatureUnit) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for TemperatureUnit {
+    /// Readings on the same scale compare directly, mixed scales through Kelvin
+    fn partial_cmp(&self, other: &TemperatureUnit) -> Option<Ordering> {
+        if self.scale() == other.scale() {
+            self.value().partial_cmp(&other.value())
+        } else {
+            self.to_kelvin().partial_cmp(&other.to_kelvin())
+        }
+    }
+}
+
+impl TemperatureUnit {
+    /// Equality within a tolerance, which absorbs rounding from cross-scale conversions
+    pub fn approx_eq(self, other: TemperatureUnit, tolerance: TemperatureInterval) -> bool {
+        (self - other).to_kelvin().abs() <= tolerance.to_kelvin().abs()
+    }
+
+    /// Total ordering through Kelvin, suitable for `sort_by`
+    pub fn total_cmp(&self, other: &TemperatureUnit) -> Ordering {
+        self.to_kelvin().total_cmp(&other.to_kelvin())
+    }
+
+    /// The colder of the two readings, keeping its own scale
+    pub fn min(self, other: TemperatureUnit) -> TemperatureUnit {
+        if other < self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// The warmer of the two readings, keeping its own scale
+    pub fn max(self, other: TemperatureUnit) -> TemperatureUnit {
+        if other > self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Restrict the reading to `[low, high]`, keeping the scale of whichever bound applies
+    pub fn clamp(self, low: TemperatureUnit, high: TemperatureUnit) -> TemperatureUnit {
+        assert!(low <= high, "clamp requires low <= high, got {} > {}", low, high);
+        self.max(low).min(high)
+    }
+
+    /// Mean of readings on mixed scales, expressed in the target scale
+    pub fn average(
+        readings: impl IntoIterator<Item = TemperatureUnit>,
+        target: TemperatureScale,
+    ) -> Option<TemperatureUnit> {
+        let (sum, count) = readings
+            .into_iter()
+            .fold((0.0, 0usize), |(sum, count), reading| (sum + reading.to_kelvin(), count + 1));
+
+        if count == 0 {
+            None
+        } else {
+            Some(TemperatureUnit::Kelvin(sum / count as f64).convert_to(target))
+        }
+    }
+}
+
+#[cfg(test)]
+mod comparison_tests {
+    use super::*;
+
+    #[test]
+    fn test_cross_scale_equality_and_ordering() {
+        assert_eq!(TemperatureUnit::Celsius(0.0), TemperatureUnit::Kelvin(273.15));
+        assert!(TemperatureUnit::Fahrenheit(100.0) > TemperatureUnit::Celsius(37.0));
+        assert!(TemperatureUnit::Kelvin(0.0) < TemperatureUnit::Fahrenheit(-459.0));
+    }
+
+    #[test]
+    fn test_approx_eq_and_sorting() {
+        let body = TemperatureUnit::Fahrenheit(98.6);
+        let tolerance = TemperatureInterval::Kelvin(1e-9);
+        assert!(body.approx_eq(TemperatureUnit::Celsius(37.0), tolerance));
+        assert!(!body.approx_eq(TemperatureUnit::Celsius(37.1), tolerance));
+
+        let mut readings = [
+            TemperatureUnit::Celsius(25.0),
+            TemperatureUnit::Kelvin(280.0),
+            TemperatureUnit::Fahrenheit(50.0),
+        ];
+        readings.sort_by(TemperatureUnit::total_cmp);
+        assert_eq!(readings[0], TemperatureUnit::Kelvin(280.0));
+        assert_eq!(readings[2], TemperatureUnit::Celsius(25.0));
+    }
+
+    #[test]
+    fn test_min_max_clamp_average() {
+        let cold = TemperatureUnit::Fahrenheit(14.0);
+        let warm = TemperatureUnit::Celsius(20.0);
+        assert_eq!(cold.min(warm), cold);
+        assert_eq!(cold.max(warm), warm);
+
+        let clamped = TemperatureUnit::Kelvin(400.0).clamp(cold, warm);
+        assert!(matches!(clamped, TemperatureUnit::Celsius(c) if c == 20.0));
+
+        let average = TemperatureUnit::average(
+            [TemperatureUnit::Celsius(10.0), TemperatureUnit::Kelvin(303.15)],
+            TemperatureScale::Celsius,
+        )
+        .unwrap();
+        assert!(average.approx_eq(TemperatureUnit::Celsius(20.0), TemperatureInterval::Kelvin(1e-9)));
+        assert_eq!(TemperatureUnit::average([], TemperatureScale::Kelvin), None);
+    }
+}
@@ -0,0 +1,286 @@
+// ============================================
+// Kata 1 extension: Conversion Tables
+// Reference charts over a range, rendered as plain text, Markdown or CSV
+// ============================================
+
+use super::{Scale, TemperatureScale, TemperatureUnit};
+use std::error::Error;
+use std::fmt;
+
+/// Slack, in steps, so that an end value reached by whole steps is not lost to rounding
+const RANGE_END_TOLERANCE: f64 = 1e-9;
+
+/// Upper bound on rows, so a huge range or tiny step fails instead of exhausting memory
+const MAX_ROWS: usize = 10_000;
+
+#[derive(Debug, PartialEq)]
+pub enum ConversionTableError {
+    InvalidRange,
+    InvalidStep,
+    NoTargetScales,
+    TooManyRows,
+}
+
+impl fmt::Display for ConversionTableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConversionTableError::InvalidRange => {
+                write!(f, "Range must be finite with start not greater than end")
+            }
+            ConversionTableError::InvalidStep => write!(f, "Step must be a positive finite number"),
+            ConversionTableError::NoTargetScales => write!(f, "At least one target scale is required"),
+            ConversionTableError::TooManyRows => {
+                write!(f, "Range and step would produce more than {} rows", MAX_ROWS)
+            }
+        }
+    }
+}
+
+impl Error for ConversionTableError {}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TableFormat {
+    PlainText,
+    Markdown,
+    Csv,
+}
+
+/// Collects the table parameters before validating them in `build`
+pub struct ConversionTableBuilder {
+    source: TemperatureScale,
+    start: f64,
+    end: f64,
+    step: f64,
+    targets: Vec<TemperatureScale>,
+}
+
+impl ConversionTableBuilder {
+    pub fn new(source: TemperatureScale) -> Self {
+        ConversionTableBuilder {
+            source,
+            start: 0.0,
+            end: 100.0,
+            step: 10.0,
+            targets: Vec::new(),
+        }
+    }
+
+    pub fn range(mut self, start: f64, end: f64) -> Self {
+        self.start = start;
+        self.end = end;
+        self
+    }
+
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+
+    pub fn target(mut self, scale: TemperatureScale) -> Self {
+        self.targets.push(scale);
+        self
+    }
+
+    pub fn targets(mut self, scales: &[TemperatureScale]) -> Self {
+        self.targets.extend_from_slice(scales);
+        self
+    }
+
+    pub fn build(self) -> Result<ConversionTable, ConversionTableError> {
+        if !self.start.is_finite() || !self.end.is_finite() || self.start > self.end {
+            return Err(ConversionTableError::InvalidRange);
+        }
+
+        if !self.step.is_finite() || self.step <= 0.0 {
+            return Err(ConversionTableError::InvalidStep);
+        }
+
+        if self.targets.is_empty() {
+            return Err(ConversionTableError::NoTargetScales);
+        }
+
+        let steps = ((self.end - self.start) / self.step + RANGE_END_TOLERANCE).floor();
+        if steps >= MAX_ROWS as f64 {
+            return Err(ConversionTableError::TooManyRows);
+        }
+
+        let rows = self
+            .source_readings(steps as usize + 1)
+            .map(|reading| {
+                let mut row = vec![reading];
+                row.extend(self.targets.iter().map(|target| reading.convert_to(*target)));
+                row
+            })
+            .collect();
+
+        let mut scales = vec![self.source];
+        scales.extend(self.targets);
+
+        Ok(ConversionTable { scales, rows })
+    }
+
+    /// Readings computed from the step index so rounding does not accumulate
+    fn source_readings(&self, count: usize) -> impl Iterator<Item = TemperatureUnit> + '_ {
+        (0..count)
+            .map(move |index| self.start + index as f64 * self.step)
+            .map(move |value| TemperatureUnit::new(self.source, value))
+    }
+}
+
+/// Rows of one source reading followed by its conversions
+pub struct ConversionTable {
+    scales: Vec<TemperatureScale>,
+    rows: Vec<Vec<TemperatureUnit>>,
+}
+
+impl ConversionTable {
+    pub fn builder(source: TemperatureScale) -> ConversionTableBuilder {
+        ConversionTableBuilder::new(source)
+    }
+
+    pub fn rows(&self) -> &[Vec<TemperatureUnit>] {
+        &self.rows
+    }
+
+    pub fn render(&self, format: TableFormat) -> String {
+        let header: Vec<String> = self
+            .scales
+            .iter()
+            .map(|scale| scale.name().to_string())
+            .collect();
+        let body: Vec<Vec<String>> = self
+            .rows
+            .iter()
+            .map(|row| row.iter().map(|cell| cell.to_string()).collect())
+            .collect();
+
+        match format {
+            TableFormat::PlainText => render_plain_text(&header, &body),
+            TableFormat::Markdown => render_markdown(&header, &body),
+            TableFormat::Csv => render_csv(&header, &body),
+        }
+    }
+}
+
+fn render_plain_text(header: &[String], body: &[Vec<String>]) -> String {
+    let widths: Vec<usize> = (0..header.len())
+        .map(|column| {
+            std::iter::once(&header[column])
+                .chain(body.iter().map(|row| &row[column]))
+                .map(|cell| cell.chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let format_row = |cells: &[String]| {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:>width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join(" | ")
+    };
+
+    let separator = widths
+        .iter()
+        .map(|width| "-".repeat(*width))
+        .collect::<Vec<_>>()
+        .join("-+-");
+
+    let mut lines = vec![format_row(header), separator];
+    lines.extend(body.iter().map(|row| format_row(row)));
+    lines.join("\n") + "\n"
+}
+
+fn render_markdown(header: &[String], body: &[Vec<String>]) -> String {
+    let format_row = |cells: &[String]| format!("| {} |", cells.join(" | "));
+    let alignment = vec!["---:".to_string(); header.len()];
+
+    let mut lines = vec![format_row(header), format_row(&alignment)];
+    lines.extend(body.iter().map(|row| format_row(row)));
+    lines.join("\n") + "\n"
+}
+
+fn render_csv(header: &[String], body: &[Vec<String>]) -> String {
+    let mut lines = vec![header.join(",")];
+    lines.extend(body.iter().map(|row| row.join(",")));
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod conversion_table_tests {
+    use super::*;
+
+    fn shop_floor_table() -> ConversionTable {
+        ConversionTable::builder(TemperatureScale::Celsius)
+            .range(-40.0, 120.0)
+            .step(5.0)
+            .targets(&[TemperatureScale::Fahrenheit, TemperatureScale::Kelvin])
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_rows_cover_inclusive_range() {
+        let table = shop_floor_table();
+        assert_eq!(table.rows().len(), 33);
+        assert_eq!(table.rows()[0][1], TemperatureUnit::Fahrenheit(-40.0));
+        assert_eq!(table.rows()[32][0], TemperatureUnit::Celsius(120.0));
+    }
+
+    #[test]
+    fn test_render_formats() {
+        let table = ConversionTable::builder(TemperatureScale::Celsius)
+            .range(0.0, 100.0)
+            .step(100.0)
+            .target(TemperatureScale::Fahrenheit)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            table.render(TableFormat::Csv),
+            "Celsius,Fahrenheit\n0.00°C,32.00°F\n100.00°C,212.00°F\n"
+        );
+        assert_eq!(
+            table.render(TableFormat::Markdown),
+            "| Celsius | Fahrenheit |\n| ---: | ---: |\n| 0.00°C | 32.00°F |\n| 100.00°C | 212.00°F |\n"
+        );
+        assert_eq!(
+            table.render(TableFormat::PlainText),
+            " Celsius | Fahrenheit\n---------+-----------\n  0.00°C |    32.00°F\n100.00°C |   212.00°F\n"
+        );
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        let builder = || ConversionTable::builder(TemperatureScale::Kelvin);
+        assert!(matches!(
+            builder().range(10.0, 0.0).target(TemperatureScale::Celsius).build(),
+            Err(ConversionTableError::InvalidRange)
+        ));
+        assert!(matches!(
+            builder().step(0.0).target(TemperatureScale::Celsius).build(),
+            Err(ConversionTableError::InvalidStep)
+        ));
+        assert!(matches!(builder().build(), Err(ConversionTableError::NoTargetScales)));
+    }
+
+    #[test]
+    fn test_row_count_is_bounded() {
+        let builder = || {
+            ConversionTable::builder(TemperatureScale::Kelvin).target(TemperatureScale::Celsius)
+        };
+        let single = builder().range(1e20, 1e20).step(1.0).build().unwrap();
+        assert_eq!(single.rows().len(), 1);
+        assert_eq!(builder().range(0.5, 1.0).step(0.1).build().unwrap().rows().len(), 6);
+        assert!(matches!(
+            builder().range(0.0, 1.0).step(1e-12).build(),
+            Err(ConversionTableError::TooManyRows)
+        ));
+        assert!(matches!(
+            builder().range(-f64::MAX, f64::MAX).step(1.0).build(),
+            Err(ConversionTableError::TooManyRows)
+        ));
+    }
+}
@@ -0,0 +1,414 @@
+// ============================================
+// Kata 1 extension: Exact Decimal Conversion
+// Rational arithmetic so conversions never drift, with explicit rounding on output
+// ============================================
+
+use super::{Scale, TemperatureScale, TemperatureUnit};
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum ExactError {
+    InvalidDecimal(String),
+    DivisionByZero,
+    Overflow,
+}
+
+impl fmt::Display for ExactError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExactError::InvalidDecimal(input) => write!(f, "Invalid decimal number: '{}'", input),
+            ExactError::DivisionByZero => write!(f, "Division by zero"),
+            ExactError::Overflow => write!(f, "Exact arithmetic overflow"),
+        }
+    }
+}
+
+impl Error for ExactError {}
+
+/// Reduced fraction with a positive denominator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    pub fn new(numerator: i128, denominator: i128) -> Result<Self, ExactError> {
+        if denominator == 0 {
+            return Err(ExactError::DivisionByZero);
+        }
+        // Reduce the magnitudes as unsigned, where `i128::MIN` has a magnitude too
+        let divisor = gcd(numerator.unsigned_abs(), denominator.unsigned_abs());
+        let magnitude = numerator.unsigned_abs() / divisor;
+        let numerator = if (numerator < 0) != (denominator < 0) {
+            0i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        };
+        let denominator = i128::try_from(denominator.unsigned_abs() / divisor).ok();
+        Ok(Rational {
+            numerator: numerator.ok_or(ExactError::Overflow)?,
+            denominator: denominator.ok_or(ExactError::Overflow)?,
+        })
+    }
+
+    pub fn integer(value: i128) -> Self {
+        Rational { numerator: value, denominator: 1 }
+    }
+
+    /// Parse a plain decimal such as `-36.6`, exactly
+    pub fn parse_decimal(input: &str) -> Result<Self, ExactError> {
+        let invalid = || ExactError::InvalidDecimal(input.to_string());
+        let trimmed = input.trim();
+        let (negative, unsigned) = match trimmed.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, trimmed.strip_prefix('+').unwrap_or(trimmed)),
+        };
+
+        let (integer_digits, fraction_digits) = unsigned
+            .split_once('.')
+            .unwrap_or((unsigned, ""));
+        let digits = format!("{}{}", integer_digits, fraction_digits);
+        if digits.is_empty() || !digits.chars().all(|ch| ch.is_ascii_digit()) {
+            return Err(invalid());
+        }
+
+        let magnitude: i128 = digits.parse().map_err(|_| ExactError::Overflow)?;
+        let scale = pow10(fraction_digits.len() as u32)?;
+        let numerator = if negative { -magnitude } else { magnitude };
+        Rational::new(numerator, scale)
+    }
+
+    pub fn numerator(self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(self) -> i128 {
+        self.denominator
+    }
+
+    pub fn checked_add(self, other: Rational) -> Result<Rational, ExactError> {
+        let numerator = self
+            .numerator
+            .checked_mul(other.denominator)
+            .and_then(|left| {
+                other
+                    .numerator
+                    .checked_mul(self.denominator)
+                    .and_then(|right| left.checked_add(right))
+            })
+            .ok_or(ExactError::Overflow)?;
+        let denominator = self
+            .denominator
+            .checked_mul(other.denominator)
+            .ok_or(ExactError::Overflow)?;
+        Rational::new(numerator, denominator)
+    }
+
+    pub fn checked_sub(self, other: Rational) -> Result<Rational, ExactError> {
+        self.checked_add(Rational::integer(-1).checked_mul(other)?)
+    }
+
+    pub fn checked_mul(self, other: Rational) -> Result<Rational, ExactError> {
+        let numerator = self
+            .numerator
+            .checked_mul(other.numerator)
+            .ok_or(ExactError::Overflow)?;
+        let denominator = self
+            .denominator
+            .checked_mul(other.denominator)
+            .ok_or(ExactError::Overflow)?;
+        Rational::new(numerator, denominator)
+    }
+
+    pub fn checked_div(self, other: Rational) -> Result<Rational, ExactError> {
+        self.checked_mul(Rational::new(other.denominator, other.numerator)?)
+    }
+
+    /// Nearest multiple of `10^-decimals` under the given rounding mode
+    pub fn round(self, decimals: u32, mode: RoundingMode) -> Result<Rational, ExactError> {
+        let scale = pow10(decimals)?;
+        let scaled = self
+            .numerator
+            .checked_mul(scale)
+            .ok_or(ExactError::Overflow)?;
+        let quotient = scaled / self.denominator;
+        let remainder = scaled % self.denominator;
+        let sign = scaled.signum();
+        let twice_remainder = remainder.abs() * 2;
+
+        let rounded = match mode {
+            RoundingMode::TowardZero => quotient,
+            RoundingMode::AwayFromZero if remainder != 0 => quotient + sign,
+            RoundingMode::Floor if remainder < 0 => quotient - 1,
+            RoundingMode::Ceiling if remainder > 0 => quotient + 1,
+            RoundingMode::HalfUp if twice_remainder >= self.denominator => quotient + sign,
+            RoundingMode::HalfEven
+                if twice_remainder > self.denominator
+                    || (twice_remainder == self.denominator && quotient % 2 != 0) =>
+            {
+                quotient + sign
+            }
+            _ => quotient,
+        };
+
+        Rational::new(rounded, scale)
+    }
+
+    /// Decimal text with exactly `decimals` fraction digits
+    pub fn to_decimal_string(
+        self,
+        decimals: u32,
+        mode: RoundingMode,
+    ) -> Result<String, ExactError> {
+        let rounded = self.round(decimals, mode)?;
+        let scale = pow10(decimals)?;
+        let scaled = rounded.numerator * (scale / rounded.denominator);
+        let sign = if scaled < 0 { "-" } else { "" };
+        let magnitude = scaled.unsigned_abs();
+        let integer_part = magnitude / scale as u128;
+
+        if decimals == 0 {
+            return Ok(format!("{}{}", sign, integer_part));
+        }
+
+        let fraction_part = magnitude % scale as u128;
+        Ok(format!(
+            "{}{}.{:0width$}",
+            sign,
+            integer_part,
+            fraction_part,
+            width = decimals as usize
+        ))
+    }
+
+    pub fn to_f64(self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.max(1)
+}
+
+fn pow10(exponent: u32) -> Result<i128, ExactError> {
+    10i128.checked_pow(exponent).ok_or(ExactError::Overflow)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RoundingMode {
+    /// Ties round away from zero (commercial rounding)
+    HalfUp,
+    /// Ties round to the even neighbour (banker's rounding)
+    HalfEven,
+    TowardZero,
+    AwayFromZero,
+    Floor,
+    Ceiling,
+}
+
+/// The scale's affine mapping to Kelvin as exact fractions: `(ice_point, degree_in_kelvin)`
+fn exact_mapping(scale: TemperatureScale) -> Result<(Rational, Rational), ExactError> {
+    let definition = scale.definition();
+    let (ice_numerator, ice_denominator) = definition.ice_point;
+    let (degree_numerator, degree_denominator) = definition.degree_in_kelvin;
+    Ok((
+        Rational::new(ice_numerator, ice_denominator)?,
+        Rational::new(degree_numerator, degree_denominator)?,
+    ))
+}
+
+/// Temperature held as an exact fraction on its scale
+///
+/// Equality is physical, as for `TemperatureUnit`: `0 °C == 273.15 K`.
+#[derive(Debug, Clone, Copy)]
+pub struct ExactTemperature {
+    value: Rational,
+    scale: TemperatureScale,
+}
+
+impl ExactTemperature {
+    pub fn new(scale: TemperatureScale, value: Rational) -> Self {
+        ExactTemperature { value, scale }
+    }
+
+    /// Create from decimal text such as `"36.6"` without passing through `f64`
+    pub fn parse_decimal(scale: TemperatureScale, value: &str) -> Result<Self, ExactError> {
+        Ok(Self::new(scale, Rational::parse_decimal(value)?))
+    }
+
+    pub fn value(self) -> Rational {
+        self.value
+    }
+
+    pub fn scale(self) -> TemperatureScale {
+        self.scale
+    }
+
+    /// Convert to a specified unit with no rounding at all
+    pub fn convert_to(self, target: TemperatureScale) -> Result<ExactTemperature, ExactError> {
+        let (source_ice_point, source_degree) = exact_mapping(self.scale)?;
+        let (target_ice_point, target_degree) = exact_mapping(target)?;
+        let value = self
+            .value
+            .checked_sub(source_ice_point)?
+            .checked_mul(source_degree)?
+            .checked_div(target_degree)?
+            .checked_add(target_ice_point)?;
+        Ok(Self::new(target, value))
+    }
+
+    /// Nearest `f64` approximation
+    pub fn to_unit(self) -> TemperatureUnit {
+        TemperatureUnit::new(self.scale, self.value.to_f64())
+    }
+
+    /// Render with a fixed number of decimals and an explicit rounding mode
+    pub fn rounded(self, decimals: u32, mode: RoundingMode) -> RoundedTemperature {
+        RoundedTemperature { temperature: self, decimals, mode }
+    }
+}
+
+impl PartialEq for ExactTemperature {
+    /// Compares through Kelvin; readings too large to convert exactly are never equal
+    fn eq(&self, other: &Self) -> bool {
+        if self.scale == other.scale {
+            return self.value == other.value;
+        }
+        let kelvin = |temperature: &ExactTemperature| {
+            temperature.convert_to(TemperatureScale::Kelvin).map(|kelvin| kelvin.value)
+        };
+        matches!((kelvin(self), kelvin(other)), (Ok(left), Ok(right)) if left == right)
+    }
+}
+
+impl TemperatureUnit {
+    /// Exact value of the shortest decimal that round-trips this reading, e.g. `36.6`
+    pub fn to_exact(self) -> Result<ExactTemperature, ExactError> {
+        ExactTemperature::parse_decimal(self.scale(), &self.value().to_string())
+    }
+}
+
+/// `Display` adapter that rounds an exact temperature as requested
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RoundedTemperature {
+    temperature: ExactTemperature,
+    decimals: u32,
+    mode: RoundingMode,
+}
+
+impl RoundedTemperature {
+    /// Exact rendering, or `Overflow` when the value or precision exceeds exact arithmetic
+    pub fn to_exact_string(self) -> Result<String, ExactError> {
+        let value = self
+            .temperature
+            .value
+            .to_decimal_string(self.decimals, self.mode)?;
+        Ok(format!("{}{}", value, self.temperature.scale.symbol()))
+    }
+}
+
+impl fmt::Display for RoundedTemperature {
+    /// Falls back to the nearest `f64` when the exact rendering would overflow
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.to_exact_string() {
+            Ok(text) => write!(f, "{}", text),
+            Err(_) => write!(f, "{:.*}", self.decimals as usize, self.temperature.to_unit()),
+        }
+    }
+}
+
+impl fmt::Display for ExactTemperature {
+    /// Honors `{:.N}` (two decimals by default), rounding half away from zero
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let decimals = f.precision().unwrap_or(2) as u32;
+        self.rounded(decimals, RoundingMode::HalfUp).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod exact_tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_round_trip_does_not_drift() {
+        let body = ExactTemperature::parse_decimal(TemperatureScale::Celsius, "36.6").unwrap();
+        let fahrenheit = body.convert_to(TemperatureScale::Fahrenheit).unwrap();
+        assert_eq!(fahrenheit.value(), Rational::parse_decimal("97.88").unwrap());
+
+        let back = fahrenheit
+            .convert_to(TemperatureScale::Kelvin)
+            .and_then(|kelvin| kelvin.convert_to(TemperatureScale::Celsius))
+            .unwrap();
+        assert_eq!(back, body);
+        assert_eq!(TemperatureUnit::Celsius(36.6).to_exact(), Ok(body));
+    }
+
+    #[test]
+    fn test_equality_is_physical() {
+        let parse = |scale, value| ExactTemperature::parse_decimal(scale, value).unwrap();
+        let freezing = parse(TemperatureScale::Celsius, "0");
+        let kelvin = parse(TemperatureScale::Kelvin, "273.15");
+        assert_eq!(freezing, kelvin);
+        assert_eq!(parse(TemperatureScale::Fahrenheit, "32"), kelvin);
+        assert_eq!(freezing.to_unit(), kelvin.to_unit());
+        assert_ne!(freezing, parse(TemperatureScale::Kelvin, "273"));
+    }
+
+    #[test]
+    fn test_extreme_values_overflow_instead_of_panicking() {
+        assert_eq!(Rational::new(i128::MIN, 1), Ok(Rational::integer(i128::MIN)));
+        assert_eq!(Rational::new(i128::MIN, i128::MIN), Ok(Rational::integer(1)));
+        assert_eq!(Rational::new(1, i128::MIN), Err(ExactError::Overflow));
+        assert_eq!(
+            Rational::integer(1).checked_div(Rational::integer(i128::MIN)),
+            Err(ExactError::Overflow)
+        );
+
+        let product = Rational::integer(-(1 << 64)).checked_mul(Rational::integer(1 << 63));
+        assert_eq!(product, Ok(Rational::integer(i128::MIN)));
+        assert_eq!(
+            product.and_then(|minimum| minimum.checked_sub(Rational::integer(1))),
+            Err(ExactError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_rounding_modes() {
+        let value = Rational::parse_decimal("2.345").unwrap();
+        let render = |mode| value.to_decimal_string(2, mode).unwrap();
+        assert_eq!(render(RoundingMode::HalfUp), "2.35");
+        assert_eq!(render(RoundingMode::HalfEven), "2.34");
+        assert_eq!(render(RoundingMode::TowardZero), "2.34");
+        assert_eq!(render(RoundingMode::AwayFromZero), "2.35");
+
+        let negative = Rational::parse_decimal("-2.345").unwrap();
+        assert_eq!(negative.to_decimal_string(2, RoundingMode::Floor).unwrap(), "-2.35");
+        assert_eq!(negative.to_decimal_string(2, RoundingMode::Ceiling).unwrap(), "-2.34");
+        assert_eq!(negative.to_decimal_string(0, RoundingMode::HalfEven).unwrap(), "-2");
+    }
+
+    #[test]
+    fn test_display_with_precision_and_mode() {
+        let kelvin = ExactTemperature::parse_decimal(TemperatureScale::Fahrenheit, "100")
+            .and_then(|fahrenheit| fahrenheit.convert_to(TemperatureScale::Kelvin))
+            .unwrap();
+        assert_eq!(kelvin.to_string(), "310.93K");
+        assert_eq!(format!("{:.4}", kelvin), "310.9278K");
+        assert_eq!(kelvin.rounded(1, RoundingMode::Floor).to_string(), "310.9K");
+        let beyond_exact_precision = format!("{:.40}", kelvin);
+        assert!(beyond_exact_precision.starts_with("310.927777"));
+        assert_eq!(
+            kelvin.rounded(40, RoundingMode::HalfUp).to_exact_string(),
+            Err(ExactError::Overflow)
+        );
+        assert_eq!(
+            Rational::parse_decimal("1.2.3"),
+            Err(ExactError::InvalidDecimal("1.2.3".to_string()))
+        );
+    }
+}
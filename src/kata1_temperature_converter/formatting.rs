@@ -0,0 +1,166 @@
+// ============================================
+// Kata 1 extension: Localized Formatting
+// Precision, decimal separator and unit naming for user-facing output
+// ============================================
+
+use super::{Scale, TemperatureScale, TemperatureUnit};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Locale {
+    English,
+    French,
+    German,
+}
+
+impl Locale {
+    fn decimal_separator(self) -> char {
+        match self {
+            Locale::English => '.',
+            Locale::French | Locale::German => ',',
+        }
+    }
+
+    fn unit_name(self, scale: TemperatureScale) -> &'static str {
+        match (self, scale) {
+            (Locale::English, TemperatureScale::Celsius) => "degrees Celsius",
+            (Locale::English, TemperatureScale::Fahrenheit) => "degrees Fahrenheit",
+            (Locale::English, TemperatureScale::Kelvin) => "kelvins",
+            (Locale::French, TemperatureScale::Celsius) => "degrés Celsius",
+            (Locale::French, TemperatureScale::Fahrenheit) => "degrés Fahrenheit",
+            (Locale::French, TemperatureScale::Kelvin) => "kelvins",
+            (Locale::German, TemperatureScale::Celsius) => "Grad Celsius",
+            (Locale::German, TemperatureScale::Fahrenheit) => "Grad Fahrenheit",
+            (Locale::German, TemperatureScale::Kelvin) => "Kelvin",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UnitStyle {
+    /// `23.50°C`
+    Symbol,
+    /// `23.50 degrees Celsius`
+    FullName,
+}
+
+/// Configurable rendering of temperatures for localized UIs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TemperatureFormatter {
+    precision: usize,
+    decimal_separator: char,
+    unit_style: UnitStyle,
+    locale: Locale,
+}
+
+impl TemperatureFormatter {
+    /// Matches the plain `Display` output: two decimals, `.` separator, symbols
+    pub fn new() -> Self {
+        Self::for_locale(Locale::English)
+    }
+
+    /// Defaults for a locale, including its decimal separator
+    pub fn for_locale(locale: Locale) -> Self {
+        TemperatureFormatter {
+            precision: 2,
+            decimal_separator: locale.decimal_separator(),
+            unit_style: UnitStyle::Symbol,
+            locale,
+        }
+    }
+
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    pub fn decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    pub fn unit_style(mut self, style: UnitStyle) -> Self {
+        self.unit_style = style;
+        self
+    }
+
+    pub fn format(&self, temperature: TemperatureUnit) -> String {
+        self.display(temperature).to_string()
+    }
+
+    /// `Display` adapter; a `{:.N}` flag overrides the configured precision
+    pub fn display(&self, temperature: TemperatureUnit) -> FormattedTemperature {
+        FormattedTemperature {
+            formatter: *self,
+            temperature,
+        }
+    }
+
+    fn render(&self, temperature: TemperatureUnit, precision: usize) -> String {
+        let number = format!("{:.*}", precision, temperature.value());
+        let number = if self.decimal_separator == '.' {
+            number
+        } else {
+            number.replace('.', &self.decimal_separator.to_string())
+        };
+
+        match self.unit_style {
+            UnitStyle::Symbol => format!("{}{}", number, temperature.scale().symbol()),
+            UnitStyle::FullName => {
+                format!("{} {}", number, self.locale.unit_name(temperature.scale()))
+            }
+        }
+    }
+}
+
+impl Default for TemperatureFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct FormattedTemperature {
+    formatter: TemperatureFormatter,
+    temperature: TemperatureUnit,
+}
+
+impl fmt::Display for FormattedTemperature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(self.formatter.precision);
+        write!(f, "{}", self.formatter.render(self.temperature, precision))
+    }
+}
+
+#[cfg(test)]
+mod formatting_tests {
+    use super::*;
+
+    #[test]
+    fn test_default_matches_display() {
+        let reading = TemperatureUnit::Kelvin(300.125);
+        assert_eq!(TemperatureFormatter::new().format(reading), reading.to_string());
+    }
+
+    #[test]
+    fn test_localized_full_names() {
+        let french = TemperatureFormatter::for_locale(Locale::French)
+            .precision(1)
+            .unit_style(UnitStyle::FullName);
+        assert_eq!(french.format(TemperatureUnit::Celsius(23.5)), "23,5 degrés Celsius");
+
+        let german = TemperatureFormatter::for_locale(Locale::German).unit_style(UnitStyle::FullName);
+        assert_eq!(german.format(TemperatureUnit::Kelvin(0.0)), "0,00 Kelvin");
+
+        let custom = TemperatureFormatter::new().decimal_separator('·');
+        assert_eq!(custom.format(TemperatureUnit::Fahrenheit(98.6)), "98·60°F");
+    }
+
+    #[test]
+    fn test_precision_flag_overrides_formatter() {
+        let formatter = TemperatureFormatter::for_locale(Locale::French);
+        let reading = TemperatureUnit::Celsius(-3.14179);
+        assert_eq!(format!("{}", formatter.display(reading)), "-3,14°C");
+        assert_eq!(format!("{:.3}", formatter.display(reading)), "-3,142°C");
+        assert_eq!(format!("{:.0}", reading), "-3°C");
+    }
+}
@@ -0,0 +1,174 @@
+// ============================================
+// Kata 1 extension: Temperature Intervals
+// Differences between readings convert by degree size only, never by offset
+// ============================================
+
+use super::{Scale, TemperatureScale, TemperatureUnit};
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Strongly-typed representation of a temperature difference
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TemperatureInterval {
+    Celsius(f64),
+    Fahrenheit(f64),
+    Kelvin(f64),
+}
+
+impl TemperatureInterval {
+    /// Create an interval on the given scale
+    pub fn new(scale: TemperatureScale, delta: f64) -> Self {
+        match scale {
+            TemperatureScale::Celsius => TemperatureInterval::Celsius(delta),
+            TemperatureScale::Fahrenheit => TemperatureInterval::Fahrenheit(delta),
+            TemperatureScale::Kelvin => TemperatureInterval::Kelvin(delta),
+        }
+    }
+
+    /// Scale the interval is expressed in
+    pub fn scale(self) -> TemperatureScale {
+        match self {
+            TemperatureInterval::Celsius(_) => TemperatureScale::Celsius,
+            TemperatureInterval::Fahrenheit(_) => TemperatureScale::Fahrenheit,
+            TemperatureInterval::Kelvin(_) => TemperatureScale::Kelvin,
+        }
+    }
+
+    /// Numeric size of the interval on its own scale
+    pub fn value(self) -> f64 {
+        match self {
+            TemperatureInterval::Celsius(delta)
+            | TemperatureInterval::Fahrenheit(delta)
+            | TemperatureInterval::Kelvin(delta) => delta,
+        }
+    }
+
+    /// Convert to Celsius degrees
+    pub fn to_celsius(self) -> f64 {
+        self.to_scale(&TemperatureScale::Celsius)
+    }
+
+    /// Convert to Fahrenheit degrees
+    pub fn to_fahrenheit(self) -> f64 {
+        self.to_scale(&TemperatureScale::Fahrenheit)
+    }
+
+    /// Convert to kelvins
+    pub fn to_kelvin(self) -> f64 {
+        self.to_scale(&TemperatureScale::Kelvin)
+    }
+
+    /// Convert to the size on any scale, including ones outside `TemperatureScale`
+    pub fn to_scale(self, target: &dyn Scale) -> f64 {
+        self.scale().convert_interval(self.value(), target)
+    }
+
+    /// Convert to a specified unit
+    pub fn convert_to(self, target: TemperatureScale) -> TemperatureInterval {
+        TemperatureInterval::new(target, self.to_scale(&target))
+    }
+}
+
+impl fmt::Display for TemperatureInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Δ{:.2}{}", self.value(), self.scale().symbol())
+    }
+}
+
+/// Absolute minus absolute yields the interval between them, in the left-hand scale
+impl Sub for TemperatureUnit {
+    type Output = TemperatureInterval;
+
+    fn sub(self, other: TemperatureUnit) -> TemperatureInterval {
+        let scale = self.scale();
+        TemperatureInterval::new(scale, self.value() - other.to_scale(&scale))
+    }
+}
+
+/// Absolute plus interval yields an absolute, in the left-hand scale
+impl Add<TemperatureInterval> for TemperatureUnit {
+    type Output = TemperatureUnit;
+
+    fn add(self, interval: TemperatureInterval) -> TemperatureUnit {
+        let scale = self.scale();
+        TemperatureUnit::new(scale, self.value() + interval.to_scale(&scale))
+    }
+}
+
+impl Sub<TemperatureInterval> for TemperatureUnit {
+    type Output = TemperatureUnit;
+
+    fn sub(self, interval: TemperatureInterval) -> TemperatureUnit {
+        self + -interval
+    }
+}
+
+impl Add for TemperatureInterval {
+    type Output = TemperatureInterval;
+
+    fn add(self, other: TemperatureInterval) -> TemperatureInterval {
+        let scale = self.scale();
+        TemperatureInterval::new(scale, self.value() + other.to_scale(&scale))
+    }
+}
+
+impl Sub for TemperatureInterval {
+    type Output = TemperatureInterval;
+
+    fn sub(self, other: TemperatureInterval) -> TemperatureInterval {
+        self + -other
+    }
+}
+
+impl Neg for TemperatureInterval {
+    type Output = TemperatureInterval;
+
+    fn neg(self) -> TemperatureInterval {
+        TemperatureInterval::new(self.scale(), -self.value())
+    }
+}
+
+impl Mul<f64> for TemperatureInterval {
+    type Output = TemperatureInterval;
+
+    fn mul(self, factor: f64) -> TemperatureInterval {
+        TemperatureInterval::new(self.scale(), self.value() * factor)
+    }
+}
+
+impl Div<f64> for TemperatureInterval {
+    type Output = TemperatureInterval;
+
+    fn div(self, divisor: f64) -> TemperatureInterval {
+        TemperatureInterval::new(self.scale(), self.value() / divisor)
+    }
+}
+
+#[cfg(test)]
+mod interval_tests {
+    use super::*;
+
+    #[test]
+    fn test_interval_conversion_ignores_offset() {
+        let rise = TemperatureInterval::Fahrenheit(9.0);
+        assert_eq!(rise.to_celsius(), 5.0);
+        assert_eq!(rise.convert_to(TemperatureScale::Kelvin), TemperatureInterval::Kelvin(5.0));
+    }
+
+    #[test]
+    fn test_absolute_minus_absolute() {
+        let difference = TemperatureUnit::Celsius(30.0) - TemperatureUnit::Kelvin(283.15);
+        assert!((difference.value() - 20.0).abs() < 1e-9);
+        assert_eq!(difference.scale(), TemperatureScale::Celsius);
+    }
+
+    #[test]
+    fn test_absolute_plus_interval() {
+        let warmed = TemperatureUnit::Celsius(20.0) + TemperatureInterval::Fahrenheit(18.0);
+        assert_eq!(warmed, TemperatureUnit::Celsius(30.0));
+
+        let cooled = TemperatureUnit::Fahrenheit(50.0) - TemperatureInterval::Celsius(10.0);
+        assert_eq!(cooled, TemperatureUnit::Fahrenheit(32.0));
+        assert_eq!(cooled.to_string(), "32.00°F");
+    }
+}
@@ -0,0 +1,162 @@
+// ============================================
+// Kata 1 extension: Parsing Temperatures
+// Human-entered strings such as `98.6°F`, `300 K` or `21.5 celsius`
+// ============================================
+
+use super::{TemperatureScale, TemperatureUnit};
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq)]
+pub enum ParseTemperatureError {
+    Empty,
+    InvalidNumber(String),
+    MissingScale,
+    UnknownScale(String),
+}
+
+impl fmt::Display for ParseTemperatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseTemperatureError::Empty => write!(f, "Temperature input is empty"),
+            ParseTemperatureError::InvalidNumber(number) => {
+                write!(f, "Invalid temperature value: '{}'", number)
+            }
+            ParseTemperatureError::MissingScale => {
+                write!(f, "Missing temperature scale, expected e.g. '°C', 'F' or 'kelvin'")
+            }
+            ParseTemperatureError::UnknownScale(scale) => {
+                write!(f, "Unknown temperature scale: '{}'", scale)
+            }
+        }
+    }
+}
+
+impl Error for ParseTemperatureError {}
+
+impl FromStr for TemperatureScale {
+    type Err = ParseTemperatureError;
+
+    /// Accepts symbols (`°C`), letter abbreviations (`C`) and full names (`celsius`)
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let trimmed = input.trim();
+        if trimmed.is_empty() {
+            return Err(ParseTemperatureError::Empty);
+        }
+
+        let name = trimmed
+            .trim_start_matches(is_degree_sign)
+            .trim_start()
+            .to_lowercase();
+
+        match name.as_str() {
+            "c" | "celsius" | "centigrade" => Ok(TemperatureScale::Celsius),
+            "f" | "fahrenheit" => Ok(TemperatureScale::Fahrenheit),
+            "k" | "kelvin" | "kelvins" => Ok(TemperatureScale::Kelvin),
+            _ => Err(ParseTemperatureError::UnknownScale(trimmed.to_string())),
+        }
+    }
+}
+
+impl FromStr for TemperatureUnit {
+    type Err = ParseTemperatureError;
+
+    /// Accepts a number followed by a scale, with optional whitespace and degree sign
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let trimmed = input.trim();
+        if trimmed.is_empty() {
+            return Err(ParseTemperatureError::Empty);
+        }
+
+        let (number, scale) = split_number_and_scale(trimmed);
+        let value = number.parse::<f64>().map_err(|_| {
+            let shown = if number.is_empty() { trimmed } else { number };
+            ParseTemperatureError::InvalidNumber(shown.to_string())
+        })?;
+
+        if scale.trim().is_empty() {
+            return Err(ParseTemperatureError::MissingScale);
+        }
+
+        Ok(TemperatureUnit::new(scale.parse()?, value))
+    }
+}
+
+fn is_degree_sign(ch: char) -> bool {
+    ch == '°' || ch == 'º'
+}
+
+/// Split after the number, including an exponent such as `e-3`; all of it is ASCII,
+/// so the split always falls on a char boundary
+fn split_number_and_scale(input: &str) -> (&str, &str) {
+    let bytes = input.as_bytes();
+    let mantissa_end = bytes
+        .iter()
+        .position(|byte| !(byte.is_ascii_digit() || matches!(byte, b'+' | b'-' | b'.')))
+        .unwrap_or(bytes.len());
+    let exponent = exponent_len(&bytes[mantissa_end..]).unwrap_or(0);
+    input.split_at(mantissa_end + exponent)
+}
+
+/// Length of an `e` or `E` exponent with at least one digit at the start of `rest`
+fn exponent_len(rest: &[u8]) -> Option<usize> {
+    let (marker, rest) = rest.split_first()?;
+    if !matches!(marker, b'e' | b'E') {
+        return None;
+    }
+    let sign = usize::from(matches!(rest.first(), Some(b'+' | b'-')));
+    let digits = rest[sign..].iter().take_while(|byte| byte.is_ascii_digit()).count();
+    (digits > 0).then_some(1 + sign + digits)
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_operator_input() {
+        assert_eq!("98.6°F".parse(), Ok(TemperatureUnit::Fahrenheit(98.6)));
+        assert_eq!("300 K".parse(), Ok(TemperatureUnit::Kelvin(300.0)));
+        assert_eq!("-40 C".parse(), Ok(TemperatureUnit::Celsius(-40.0)));
+        assert_eq!(" 21.5 celsius ".parse(), Ok(TemperatureUnit::Celsius(21.5)));
+        assert_eq!("10 ° Fahrenheit".parse(), Ok(TemperatureUnit::Fahrenheit(10.0)));
+    }
+
+    #[test]
+    fn test_parse_exponents() {
+        assert_eq!("1.5e2 K".parse(), Ok(TemperatureUnit::Kelvin(150.0)));
+        assert_eq!("-2.5E-1°C".parse(), Ok(TemperatureUnit::Celsius(-0.25)));
+        assert_eq!("3e+1F".parse(), Ok(TemperatureUnit::Fahrenheit(30.0)));
+        assert_eq!(
+            "2e K".parse::<TemperatureUnit>(),
+            Err(ParseTemperatureError::UnknownScale("e K".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        for temperature in [
+            TemperatureUnit::Celsius(-12.25),
+            TemperatureUnit::Fahrenheit(451.0),
+            TemperatureUnit::Kelvin(0.5),
+        ] {
+            assert_eq!(temperature.to_string().parse(), Ok(temperature));
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!("".parse::<TemperatureUnit>(), Err(ParseTemperatureError::Empty));
+        assert_eq!("42".parse::<TemperatureUnit>(), Err(ParseTemperatureError::MissingScale));
+        assert_eq!(
+            "abc C".parse::<TemperatureUnit>(),
+            Err(ParseTemperatureError::InvalidNumber("abc C".to_string()))
+        );
+        assert_eq!(
+            "12 parsecs".parse::<TemperatureUnit>(),
+            Err(ParseTemperatureError::UnknownScale("parsecs".to_string()))
+        );
+        assert_eq!("°F".parse(), Ok(TemperatureScale::Fahrenheit));
+    }
+}
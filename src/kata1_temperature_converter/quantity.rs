@@ -0,0 +1,247 @@
+// ============================================
+// Kata 1 extension: Generic Quantities
+// The affine conversion pattern applied to any dimension, temperature included
+// ============================================
+
+use super::{ICE_POINT_KELVIN, TemperatureScale, TemperatureUnit};
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dimension {
+    Length,
+    Mass,
+    Pressure,
+    Energy,
+    Temperature,
+}
+
+impl fmt::Display for Dimension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Dimension::Length => write!(f, "length"),
+            Dimension::Mass => write!(f, "mass"),
+            Dimension::Pressure => write!(f, "pressure"),
+            Dimension::Energy => write!(f, "energy"),
+            Dimension::Temperature => write!(f, "temperature"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum QuantityError {
+    DimensionMismatch(Dimension, Dimension),
+}
+
+impl fmt::Display for QuantityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QuantityError::DimensionMismatch(from, to) => {
+                write!(f, "Cannot convert {} to {}", from, to)
+            }
+        }
+    }
+}
+
+impl Error for QuantityError {}
+
+/// A unit defined by its affine mapping to the SI base unit: `base = value * factor + offset`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Unit {
+    pub name: &'static str,
+    pub symbol: &'static str,
+    pub dimension: Dimension,
+    pub factor: f64,
+    pub offset: f64,
+}
+
+impl Unit {
+    /// A unit that is a plain multiple of the base unit
+    pub const fn linear(
+        name: &'static str,
+        symbol: &'static str,
+        dimension: Dimension,
+        factor: f64,
+    ) -> Self {
+        Unit { name, symbol, dimension, factor, offset: 0.0 }
+    }
+
+    /// A built-in temperature scale, from the same definition its `Scale` impl uses
+    pub const fn temperature(scale: TemperatureScale) -> Self {
+        let definition = scale.definition();
+        let (ice_numerator, ice_denominator) = definition.ice_point;
+        let (degree_numerator, degree_denominator) = definition.degree_in_kelvin;
+        let factor = degree_numerator as f64 / degree_denominator as f64;
+        Unit {
+            name: definition.name,
+            symbol: definition.symbol,
+            dimension: Dimension::Temperature,
+            factor,
+            offset: ICE_POINT_KELVIN - ice_numerator as f64 / ice_denominator as f64 * factor,
+        }
+    }
+
+    pub fn to_base(self, value: f64) -> f64 {
+        value * self.factor + self.offset
+    }
+
+    pub fn value_from_base(self, base: f64) -> f64 {
+        (base - self.offset) / self.factor
+    }
+}
+
+pub mod units {
+    use super::{Dimension, TemperatureScale, Unit};
+
+    pub const METER: Unit = Unit::linear("meter", "m", Dimension::Length, 1.0);
+    pub const KILOMETER: Unit = Unit::linear("kilometer", "km", Dimension::Length, 1000.0);
+    pub const CENTIMETER: Unit = Unit::linear("centimeter", "cm", Dimension::Length, 0.01);
+    pub const INCH: Unit = Unit::linear("inch", "in", Dimension::Length, 0.0254);
+    pub const FOOT: Unit = Unit::linear("foot", "ft", Dimension::Length, 0.3048);
+    pub const MILE: Unit = Unit::linear("mile", "mi", Dimension::Length, 1609.344);
+
+    pub const KILOGRAM: Unit = Unit::linear("kilogram", "kg", Dimension::Mass, 1.0);
+    pub const GRAM: Unit = Unit::linear("gram", "g", Dimension::Mass, 0.001);
+    pub const POUND: Unit = Unit::linear("pound", "lb", Dimension::Mass, 0.453_592_37);
+    pub const OUNCE: Unit = Unit::linear("ounce", "oz", Dimension::Mass, 0.028_349_523_125);
+
+    pub const PASCAL: Unit = Unit::linear("pascal", "Pa", Dimension::Pressure, 1.0);
+    pub const KILOPASCAL: Unit = Unit::linear("kilopascal", "kPa", Dimension::Pressure, 1000.0);
+    pub const BAR: Unit = Unit::linear("bar", "bar", Dimension::Pressure, 100_000.0);
+    pub const ATMOSPHERE: Unit =
+        Unit::linear("atmosphere", "atm", Dimension::Pressure, 101_325.0);
+    pub const PSI: Unit =
+        Unit::linear("pound per square inch", "psi", Dimension::Pressure, 6_894.757_293);
+
+    pub const JOULE: Unit = Unit::linear("joule", "J", Dimension::Energy, 1.0);
+    pub const KILOJOULE: Unit = Unit::linear("kilojoule", "kJ", Dimension::Energy, 1000.0);
+    pub const CALORIE: Unit = Unit::linear("calorie", "cal", Dimension::Energy, 4.184);
+    pub const KILOWATT_HOUR: Unit =
+        Unit::linear("kilowatt-hour", "kWh", Dimension::Energy, 3.6e6);
+    pub const BTU: Unit =
+        Unit::linear("British thermal unit", "BTU", Dimension::Energy, 1_055.055_852_62);
+
+    pub const KELVIN: Unit = Unit::temperature(TemperatureScale::Kelvin);
+    pub const CELSIUS: Unit = Unit::temperature(TemperatureScale::Celsius);
+    pub const FAHRENHEIT: Unit = Unit::temperature(TemperatureScale::Fahrenheit);
+}
+
+/// A value tagged with its unit
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quantity {
+    value: f64,
+    unit: Unit,
+}
+
+impl Quantity {
+    pub fn new(value: f64, unit: Unit) -> Self {
+        Quantity { value, unit }
+    }
+
+    pub fn value(self) -> f64 {
+        self.value
+    }
+
+    pub fn unit(self) -> Unit {
+        self.unit
+    }
+
+    pub fn dimension(self) -> Dimension {
+        self.unit.dimension
+    }
+
+    /// Value in the SI base unit of the dimension
+    pub fn to_base(self) -> f64 {
+        self.unit.to_base(self.value)
+    }
+
+    /// Convert to another unit of the same dimension
+    pub fn convert_to(self, target: Unit) -> Result<Quantity, QuantityError> {
+        if self.unit.dimension != target.dimension {
+            return Err(QuantityError::DimensionMismatch(self.unit.dimension, target.dimension));
+        }
+        Ok(Quantity::new(target.value_from_base(self.to_base()), target))
+    }
+}
+
+impl fmt::Display for Quantity {
+    /// Two decimals by default; temperature symbols attach to the number like
+    /// `TemperatureUnit`'s output, others are spaced
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(2);
+        let separator = if self.unit.dimension == Dimension::Temperature {
+            ""
+        } else {
+            " "
+        };
+        write!(f, "{:.*}{}{}", precision, self.value, separator, self.unit.symbol)
+    }
+}
+
+impl From<TemperatureScale> for Unit {
+    fn from(scale: TemperatureScale) -> Self {
+        Unit::temperature(scale)
+    }
+}
+
+impl From<TemperatureUnit> for Quantity {
+    fn from(temperature: TemperatureUnit) -> Self {
+        Quantity::new(temperature.value(), temperature.scale().into())
+    }
+}
+
+impl TryFrom<Quantity> for TemperatureUnit {
+    type Error = QuantityError;
+
+    /// Keeps the Celsius or Fahrenheit unit when possible, otherwise converts to Kelvin
+    fn try_from(quantity: Quantity) -> Result<Self, Self::Error> {
+        let scale = [TemperatureScale::Celsius, TemperatureScale::Fahrenheit]
+            .into_iter()
+            .find(|scale| Unit::from(*scale) == quantity.unit)
+            .unwrap_or(TemperatureScale::Kelvin);
+        let converted = quantity.convert_to(scale.into())?;
+        Ok(TemperatureUnit::new(scale, converted.value()))
+    }
+}
+
+#[cfg(test)]
+mod quantity_tests {
+    use super::units::*;
+    use super::*;
+
+    #[test]
+    fn test_linear_conversions() {
+        let marathon = Quantity::new(26.2188, MILE).convert_to(KILOMETER).unwrap();
+        assert!((marathon.value() - 42.195).abs() < 0.001);
+
+        let tire = Quantity::new(32.0, PSI).convert_to(BAR).unwrap();
+        assert!((tire.value() - 2.206).abs() < 0.001);
+        let grams = Quantity::new(1.5, KILOGRAM).convert_to(GRAM).unwrap();
+        assert_eq!(grams.to_string(), "1500.00 g");
+    }
+
+    #[test]
+    fn test_dimension_mismatch() {
+        assert_eq!(
+            Quantity::new(1.0, JOULE).convert_to(METER),
+            Err(QuantityError::DimensionMismatch(Dimension::Energy, Dimension::Length))
+        );
+    }
+
+    #[test]
+    fn test_temperature_is_a_quantity() {
+        let boiling = Quantity::from(TemperatureUnit::Celsius(100.0));
+        let fahrenheit = boiling.convert_to(FAHRENHEIT).unwrap();
+        assert!((fahrenheit.value() - 212.0).abs() < 1e-9);
+        assert_eq!(fahrenheit.to_string(), "212.00°F");
+        let kelvin = boiling.convert_to(KELVIN).unwrap();
+        assert_eq!(kelvin.to_string(), "373.15K");
+        assert_eq!(kelvin.to_string(), TemperatureUnit::try_from(kelvin).unwrap().to_string());
+        assert_eq!(CELSIUS.offset, 273.15);
+        assert_eq!(FAHRENHEIT.offset, 273.15 - 32.0 * 5.0 / 9.0);
+
+        let back = TemperatureUnit::try_from(fahrenheit).unwrap();
+        assert_eq!(back.scale(), TemperatureScale::Fahrenheit);
+        assert!(TemperatureUnit::try_from(Quantity::new(1.0, METER)).is_err());
+    }
+}
@@ -0,0 +1,227 @@
+// ============================================
+// Kata 1 extension: Scale Registry
+// Legacy scales and any-to-any conversion through the `Scale` trait
+// ============================================
+
+use super::{Scale, TemperatureScale};
+use std::error::Error;
+use std::fmt;
+
+/// Rankine: absolute scale with Fahrenheit-sized degrees
+pub struct Rankine;
+
+/// Réaumur: 0°Ré at the ice point, 80°Ré at the boiling point
+pub struct Reaumur;
+
+/// Delisle: inverted scale, 0°De at the boiling point, 150°De at the ice point
+pub struct Delisle;
+
+/// Newton: 0°N at the ice point, 33°N at the boiling point
+pub struct Newton;
+
+/// Rømer: 7.5°Rø at the ice point, 60°Rø at the boiling point
+pub struct Romer;
+
+impl Scale for Rankine {
+    fn name(&self) -> &str {
+        "Rankine"
+    }
+
+    fn symbol(&self) -> &str {
+        "°R"
+    }
+
+    fn ice_point(&self) -> f64 {
+        491.67
+    }
+
+    fn degree_in_kelvin(&self) -> (f64, f64) {
+        (5.0, 9.0)
+    }
+}
+
+impl Scale for Reaumur {
+    fn name(&self) -> &str {
+        "Réaumur"
+    }
+
+    fn symbol(&self) -> &str {
+        "°Ré"
+    }
+
+    fn ice_point(&self) -> f64 {
+        0.0
+    }
+
+    fn degree_in_kelvin(&self) -> (f64, f64) {
+        (5.0, 4.0)
+    }
+}
+
+impl Scale for Delisle {
+    fn name(&self) -> &str {
+        "Delisle"
+    }
+
+    fn symbol(&self) -> &str {
+        "°De"
+    }
+
+    fn ice_point(&self) -> f64 {
+        150.0
+    }
+
+    fn degree_in_kelvin(&self) -> (f64, f64) {
+        (-2.0, 3.0)
+    }
+}
+
+impl Scale for Newton {
+    fn name(&self) -> &str {
+        "Newton"
+    }
+
+    fn symbol(&self) -> &str {
+        "°N"
+    }
+
+    fn ice_point(&self) -> f64 {
+        0.0
+    }
+
+    fn degree_in_kelvin(&self) -> (f64, f64) {
+        (100.0, 33.0)
+    }
+}
+
+impl Scale for Romer {
+    fn name(&self) -> &str {
+        "Rømer"
+    }
+
+    fn symbol(&self) -> &str {
+        "°Rø"
+    }
+
+    fn ice_point(&self) -> f64 {
+        7.5
+    }
+
+    fn degree_in_kelvin(&self) -> (f64, f64) {
+        (40.0, 21.0)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ScaleRegistryError {
+    UnknownScale(String),
+}
+
+impl fmt::Display for ScaleRegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaleRegistryError::UnknownScale(key) => write!(f, "Unknown temperature scale: {}", key),
+        }
+    }
+}
+
+impl Error for ScaleRegistryError {}
+
+/// Collection of scales looked up by name or symbol
+pub struct ScaleRegistry {
+    scales: Vec<Box<dyn Scale>>,
+}
+
+impl ScaleRegistry {
+    /// Registry with no scales; `default()` has the built-in ones
+    pub fn empty() -> Self {
+        ScaleRegistry { scales: Vec::new() }
+    }
+
+    /// Registry pre-populated with every scale shipped by this kata
+    pub fn with_builtin_scales() -> Self {
+        let mut registry = Self::empty();
+        registry.register(Box::new(TemperatureScale::Celsius));
+        registry.register(Box::new(TemperatureScale::Fahrenheit));
+        registry.register(Box::new(TemperatureScale::Kelvin));
+        registry.register(Box::new(Rankine));
+        registry.register(Box::new(Reaumur));
+        registry.register(Box::new(Delisle));
+        registry.register(Box::new(Newton));
+        registry.register(Box::new(Romer));
+        registry
+    }
+
+    pub fn register(&mut self, scale: Box<dyn Scale>) {
+        self.scales.push(scale);
+    }
+
+    /// Find a scale by its name (case-insensitive) or exact symbol
+    pub fn find(&self, key: &str) -> Option<&dyn Scale> {
+        let lowercase_key = key.to_lowercase();
+        self.scales
+            .iter()
+            .map(|scale| scale.as_ref())
+            .find(|scale| scale.symbol() == key || scale.name().to_lowercase() == lowercase_key)
+    }
+
+    pub fn scales(&self) -> impl Iterator<Item = &dyn Scale> {
+        self.scales.iter().map(|scale| scale.as_ref())
+    }
+
+    /// Convert a reading between two registered scales
+    pub fn convert(&self, value: f64, from: &str, to: &str) -> Result<f64, ScaleRegistryError> {
+        let source = self.lookup(from)?;
+        let target = self.lookup(to)?;
+        Ok(source.convert_value(value, target))
+    }
+
+    fn lookup(&self, key: &str) -> Result<&dyn Scale, ScaleRegistryError> {
+        self.find(key)
+            .ok_or_else(|| ScaleRegistryError::UnknownScale(key.to_string()))
+    }
+}
+
+impl Default for ScaleRegistry {
+    fn default() -> Self {
+        Self::with_builtin_scales()
+    }
+}
+
+#[cfg(test)]
+mod scale_registry_tests {
+    use super::*;
+    use crate::kata1_temperature_converter::TemperatureUnit;
+
+    #[test]
+    fn test_legacy_scales_at_boiling_point() {
+        let boiling = TemperatureUnit::Celsius(100.0);
+        assert!((boiling.to_scale(&Rankine) - 671.67).abs() < 1e-9);
+        assert!((boiling.to_scale(&Reaumur) - 80.0).abs() < 1e-9);
+        assert!((boiling.to_scale(&Delisle) - 0.0).abs() < 1e-9);
+        assert!((boiling.to_scale(&Newton) - 33.0).abs() < 1e-9);
+        assert!((boiling.to_scale(&Romer) - 60.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_registry_any_to_any_conversion() {
+        let registry = ScaleRegistry::with_builtin_scales();
+        let kelvin = registry.convert(150.0, "Delisle", "K").unwrap();
+        assert!((kelvin - 273.15).abs() < 1e-9);
+
+        let romer = registry.convert(80.0, "°Ré", "rømer").unwrap();
+        assert!((romer - 60.0).abs() < 1e-9);
+        assert_eq!(registry.find("RØMER").map(|scale| scale.symbol()), Some("°Rø"));
+        assert_eq!(registry.find("RÉAUMUR").map(|scale| scale.name()), Some("Réaumur"));
+    }
+
+    #[test]
+    fn test_registry_unknown_scale() {
+        let registry = ScaleRegistry::empty();
+        assert_eq!(
+            registry.convert(0.0, "Celsius", "Kelvin"),
+            Err(ScaleRegistryError::UnknownScale("Celsius".to_string()))
+        );
+        assert!(ScaleRegistry::default().find("Celsius").is_some());
+    }
+}
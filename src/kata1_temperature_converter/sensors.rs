@@ -0,0 +1,504 @@
+// ============================================
+// Kata 1 extension: Sensor Conversion
+// Thermistor resistance and thermocouple voltage to temperature
+// ============================================
+
+use super::{TemperatureScale, TemperatureUnit};
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum SensorError {
+    InvalidResistance(f64),
+    InvalidCalibration(String),
+    VoltageOutOfRange(f64),
+    TemperatureOutOfRange(TemperatureUnit),
+}
+
+impl fmt::Display for SensorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SensorError::InvalidResistance(ohms) => {
+                write!(f, "Resistance must be a positive number of ohms, got {}", ohms)
+            }
+            SensorError::InvalidCalibration(msg) => write!(f, "Invalid calibration: {}", msg),
+            SensorError::VoltageOutOfRange(millivolts) => {
+                write!(f, "Voltage {} mV is outside the thermocouple's range", millivolts)
+            }
+            SensorError::TemperatureOutOfRange(temperature) => {
+                write!(f, "Temperature {} is outside the sensor's range", temperature)
+            }
+        }
+    }
+}
+
+impl Error for SensorError {}
+
+/// Thermistor model `1/T = A + B ln(R) + C ln(R)^3`, with `T` in Kelvin
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SteinhartHart {
+    a: f64,
+    b: f64,
+    c: f64,
+}
+
+impl SteinhartHart {
+    pub fn new(a: f64, b: f64, c: f64) -> Self {
+        SteinhartHart { a, b, c }
+    }
+
+    /// Fit the coefficients from three `(resistance in ohms, temperature)` calibration points
+    pub fn fit(points: [(f64, TemperatureUnit); 3]) -> Result<Self, SensorError> {
+        let mut logs = [0.0; 3];
+        let mut inverses = [0.0; 3];
+        for (index, (resistance, temperature)) in points.iter().enumerate() {
+            logs[index] = validate_resistance(*resistance)?.ln();
+            let kelvin = temperature.to_kelvin();
+            if !kelvin.is_finite() || kelvin <= 0.0 {
+                return Err(SensorError::InvalidCalibration(format!(
+                    "temperature {} must be above absolute zero",
+                    temperature
+                )));
+            }
+            inverses[index] = 1.0 / kelvin;
+        }
+
+        let [l1, l2, l3] = logs;
+        let [y1, y2, y3] = inverses;
+        if l1 == l2 || l1 == l3 || l2 == l3 {
+            return Err(SensorError::InvalidCalibration(
+                "calibration resistances must be distinct".to_string(),
+            ));
+        }
+
+        let log_sum = l1 + l2 + l3;
+        if log_sum == 0.0 {
+            return Err(SensorError::InvalidCalibration(
+                "logarithms of the calibration resistances must not sum to zero".to_string(),
+            ));
+        }
+
+        let gamma2 = (y2 - y1) / (l2 - l1);
+        let gamma3 = (y3 - y1) / (l3 - l1);
+        let c = (gamma3 - gamma2) / (l3 - l2) / log_sum;
+        let b = gamma2 - c * (l1 * l1 + l1 * l2 + l2 * l2);
+        let a = y1 - (b + l1 * l1 * c) * l1;
+        if !(a.is_finite() && b.is_finite() && c.is_finite()) {
+            return Err(SensorError::InvalidCalibration(
+                "calibration points give non-finite coefficients".to_string(),
+            ));
+        }
+
+        Ok(Self::new(a, b, c))
+    }
+
+    pub fn coefficients(&self) -> (f64, f64, f64) {
+        (self.a, self.b, self.c)
+    }
+
+    /// Temperature for a measured resistance in ohms
+    pub fn temperature(
+        &self,
+        resistance: f64,
+        output: TemperatureScale,
+    ) -> Result<TemperatureUnit, SensorError> {
+        let ln_r = validate_resistance(resistance)?.ln();
+        let kelvin = 1.0 / (self.a + self.b * ln_r + self.c * ln_r.powi(3));
+        if !kelvin.is_finite() || kelvin <= 0.0 {
+            return Err(SensorError::TemperatureOutOfRange(TemperatureUnit::Kelvin(kelvin)));
+        }
+        Ok(TemperatureUnit::Kelvin(kelvin).convert_to(output))
+    }
+}
+
+fn validate_resistance(resistance: f64) -> Result<f64, SensorError> {
+    if resistance.is_finite() && resistance > 0.0 {
+        Ok(resistance)
+    } else {
+        Err(SensorError::InvalidResistance(resistance))
+    }
+}
+
+/// A polynomial valid over `[lower, upper]` of its input
+struct PolynomialRange {
+    lower: f64,
+    upper: f64,
+    coefficients: &'static [f64],
+}
+
+impl PolynomialRange {
+    fn evaluate(&self, x: f64) -> f64 {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(0.0, |acc, coefficient| acc * x + coefficient)
+    }
+}
+
+fn find_range(ranges: &'static [PolynomialRange], x: f64) -> Option<&'static PolynomialRange> {
+    ranges
+        .iter()
+        .find(|range| x >= range.lower && x <= range.upper)
+}
+
+// NIST ITS-90 reference functions: temperature in °C to EMF in mV
+const TYPE_K_FORWARD: &[PolynomialRange] = &[
+    PolynomialRange {
+        lower: -270.0,
+        upper: 0.0,
+        coefficients: &[
+            0.0,
+            0.394501280250E-01,
+            0.236223735980E-04,
+            -0.328589067840E-06,
+            -0.499048287770E-08,
+            -0.675090591730E-10,
+            -0.574103274280E-12,
+            -0.310888728940E-14,
+            -0.104516093650E-16,
+            -0.198892668780E-19,
+            -0.163226974860E-22,
+        ],
+    },
+    PolynomialRange {
+        lower: 0.0,
+        upper: 1372.0,
+        coefficients: &[
+            -0.176004136860E-01,
+            0.389212049750E-01,
+            0.185587700320E-04,
+            -0.994575928740E-07,
+            0.318409457190E-09,
+            -0.560728448890E-12,
+            0.560750590590E-15,
+            -0.320207200030E-18,
+            0.971511471520E-22,
+            -0.121047212750E-25,
+        ],
+    },
+];
+
+/// Exponential correction `a0 * exp(a1 * (t - a2)^2)` of type K above 0°C
+const TYPE_K_EXPONENTIAL: (f64, f64, f64) =
+    (0.118597600000E+00, -0.118343200000E-03, 0.126968600000E+03);
+
+const TYPE_J_FORWARD: &[PolynomialRange] = &[PolynomialRange {
+    lower: -210.0,
+    upper: 760.0,
+    coefficients: &[
+        0.0,
+        0.503811878150E-01,
+        0.304758369300E-04,
+        -0.856810657200E-07,
+        0.132281952950E-09,
+        -0.170529583370E-12,
+        0.209480906970E-15,
+        -0.125383953360E-18,
+        0.156317256970E-22,
+    ],
+}];
+
+const TYPE_T_FORWARD: &[PolynomialRange] = &[
+    PolynomialRange {
+        lower: -270.0,
+        upper: 0.0,
+        coefficients: &[
+            0.0,
+            0.387481063640E-01,
+            0.441944343470E-04,
+            0.118443231050E-06,
+            0.200329735540E-07,
+            0.901380195590E-09,
+            0.226511565930E-10,
+            0.360711542050E-12,
+            0.384939398830E-14,
+            0.282135219250E-16,
+            0.142515947790E-18,
+            0.487686622860E-21,
+            0.107955392700E-23,
+            0.139450270620E-26,
+            0.797951539270E-30,
+        ],
+    },
+    PolynomialRange {
+        lower: 0.0,
+        upper: 400.0,
+        coefficients: &[
+            0.0,
+            0.387481063640E-01,
+            0.332922278800E-04,
+            0.206182434040E-06,
+            -0.218822568460E-08,
+            0.109968809280E-10,
+            -0.308157587720E-13,
+            0.454791352900E-16,
+            -0.275129016730E-19,
+        ],
+    },
+];
+
+// NIST ITS-90 inverse functions: EMF in mV to temperature in °C
+const TYPE_K_INVERSE: &[PolynomialRange] = &[
+    PolynomialRange {
+        lower: -5.891,
+        upper: 0.0,
+        coefficients: &[
+            0.0,
+            2.5173462E+01,
+            -1.1662878E+00,
+            -1.0833638E+00,
+            -8.9773540E-01,
+            -3.7342377E-01,
+            -8.6632643E-02,
+            -1.0450598E-02,
+            -5.1920577E-04,
+        ],
+    },
+    PolynomialRange {
+        lower: 0.0,
+        upper: 20.644,
+        coefficients: &[
+            0.0,
+            2.508355E+01,
+            7.860106E-02,
+            -2.503131E-01,
+            8.315270E-02,
+            -1.228034E-02,
+            9.804036E-04,
+            -4.413030E-05,
+            1.057734E-06,
+            -1.052755E-08,
+        ],
+    },
+    PolynomialRange {
+        lower: 20.644,
+        upper: 54.886,
+        coefficients: &[
+            -1.318058E+02,
+            4.830222E+01,
+            -1.646031E+00,
+            5.464731E-02,
+            -9.650715E-04,
+            8.802193E-06,
+            -3.110810E-08,
+        ],
+    },
+];
+
+const TYPE_J_INVERSE: &[PolynomialRange] = &[
+    PolynomialRange {
+        lower: -8.095,
+        upper: 0.0,
+        coefficients: &[
+            0.0,
+            1.9528268E+01,
+            -1.2286185E+00,
+            -1.0752178E+00,
+            -5.9086933E-01,
+            -1.7256713E-01,
+            -2.8131513E-02,
+            -2.3963370E-03,
+            -8.3823321E-05,
+        ],
+    },
+    PolynomialRange {
+        lower: 0.0,
+        upper: 42.919,
+        coefficients: &[
+            0.0,
+            1.978425E+01,
+            -2.001204E-01,
+            1.036969E-02,
+            -2.549687E-04,
+            3.585153E-06,
+            -5.344285E-08,
+            5.099890E-10,
+        ],
+    },
+];
+
+const TYPE_T_INVERSE: &[PolynomialRange] = &[
+    PolynomialRange {
+        lower: -5.603,
+        upper: 0.0,
+        coefficients: &[
+            0.0,
+            2.5949192E+01,
+            -2.1316967E-01,
+            7.9018692E-01,
+            4.2527777E-01,
+            1.3304473E-01,
+            2.0241446E-02,
+            1.2668171E-03,
+        ],
+    },
+    PolynomialRange {
+        lower: 0.0,
+        upper: 20.872,
+        coefficients: &[
+            0.0,
+            2.592800E+01,
+            -7.602961E-01,
+            4.637791E-02,
+            -2.165394E-03,
+            6.048144E-05,
+            -7.293422E-07,
+        ],
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ThermocoupleType {
+    K,
+    J,
+    T,
+}
+
+impl ThermocoupleType {
+    fn forward(self) -> &'static [PolynomialRange] {
+        match self {
+            ThermocoupleType::K => TYPE_K_FORWARD,
+            ThermocoupleType::J => TYPE_J_FORWARD,
+            ThermocoupleType::T => TYPE_T_FORWARD,
+        }
+    }
+
+    fn inverse(self) -> &'static [PolynomialRange] {
+        match self {
+            ThermocoupleType::K => TYPE_K_INVERSE,
+            ThermocoupleType::J => TYPE_J_INVERSE,
+            ThermocoupleType::T => TYPE_T_INVERSE,
+        }
+    }
+}
+
+/// Thermocouple conversion with a 0°C reference per NIST ITS-90
+pub struct Thermocouple {
+    kind: ThermocoupleType,
+}
+
+impl Thermocouple {
+    pub fn new(kind: ThermocoupleType) -> Self {
+        Thermocouple { kind }
+    }
+
+    /// EMF in millivolts produced against a 0°C reference junction
+    pub fn voltage_at(&self, temperature: TemperatureUnit) -> Result<f64, SensorError> {
+        let celsius = temperature.to_celsius();
+        let range = find_range(self.kind.forward(), celsius)
+            .ok_or(SensorError::TemperatureOutOfRange(temperature))?;
+
+        let mut millivolts = range.evaluate(celsius);
+        if self.kind == ThermocoupleType::K && celsius > 0.0 {
+            let (a0, a1, a2) = TYPE_K_EXPONENTIAL;
+            millivolts += a0 * (a1 * (celsius - a2).powi(2)).exp();
+        }
+        Ok(millivolts)
+    }
+
+    /// Hot-junction temperature from the measured voltage and the cold-junction temperature
+    pub fn temperature(
+        &self,
+        measured_millivolts: f64,
+        cold_junction: TemperatureUnit,
+        output: TemperatureScale,
+    ) -> Result<TemperatureUnit, SensorError> {
+        let compensated = measured_millivolts + self.voltage_at(cold_junction)?;
+        let range = find_range(self.kind.inverse(), compensated)
+            .ok_or(SensorError::VoltageOutOfRange(compensated))?;
+
+        Ok(TemperatureUnit::Celsius(range.evaluate(compensated)).convert_to(output))
+    }
+}
+
+#[cfg(test)]
+mod sensor_tests {
+    use super::*;
+
+    #[test]
+    fn test_steinhart_hart_fit_recovers_coefficients() {
+        let reference = SteinhartHart::new(1.009249522e-3, 2.378405444e-4, 2.019202697e-7);
+        let points = [1_000.0, 10_000.0, 100_000.0]
+            .map(|ohms| (ohms, reference.temperature(ohms, TemperatureScale::Kelvin).unwrap()));
+
+        let fitted = SteinhartHart::fit(points).unwrap();
+        let room = fitted.temperature(10_000.0, TemperatureScale::Celsius).unwrap();
+        assert!((room.value() - 24.68).abs() < 0.01);
+
+        let (a, b, c) = fitted.coefficients();
+        assert!((a - 1.009249522e-3).abs() < 1e-12);
+        assert!((b - 2.378405444e-4).abs() < 1e-12);
+        assert!((c - 2.019202697e-7).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_steinhart_hart_rejects_bad_input() {
+        let point = (10_000.0, TemperatureUnit::Celsius(25.0));
+        assert!(matches!(
+            SteinhartHart::fit([point, point, (5_000.0, TemperatureUnit::Celsius(40.0))]),
+            Err(SensorError::InvalidCalibration(_))
+        ));
+        let model = SteinhartHart::new(1e-3, 2e-4, 2e-7);
+        assert_eq!(
+            model.temperature(-1.0, TemperatureScale::Celsius),
+            Err(SensorError::InvalidResistance(-1.0))
+        );
+    }
+
+    #[test]
+    fn test_steinhart_hart_rejects_degenerate_results() {
+        let room = TemperatureUnit::Celsius(25.0);
+        assert!(matches!(
+            SteinhartHart::fit([(0.5, room), (1.0, room), (2.0, room)]),
+            Err(SensorError::InvalidCalibration(_))
+        ));
+        assert_eq!(
+            SteinhartHart::new(-1e-3, 0.0, 0.0).temperature(100.0, TemperatureScale::Celsius),
+            Err(SensorError::TemperatureOutOfRange(TemperatureUnit::Kelvin(-1000.0)))
+        );
+        assert_eq!(
+            SteinhartHart::new(0.0, 0.0, 0.0).temperature(100.0, TemperatureScale::Celsius),
+            Err(SensorError::TemperatureOutOfRange(TemperatureUnit::Kelvin(f64::INFINITY)))
+        );
+    }
+
+    #[test]
+    fn test_thermocouple_reference_voltages() {
+        let boiling = TemperatureUnit::Celsius(100.0);
+        let k = Thermocouple::new(ThermocoupleType::K).voltage_at(boiling).unwrap();
+        let j = Thermocouple::new(ThermocoupleType::J).voltage_at(boiling).unwrap();
+        let t = Thermocouple::new(ThermocoupleType::T).voltage_at(boiling).unwrap();
+        assert!((k - 4.096).abs() < 0.001);
+        assert!((j - 5.269).abs() < 0.001);
+        assert!((t - 4.279).abs() < 0.001);
+
+        let cold = Thermocouple::new(ThermocoupleType::K)
+            .voltage_at(TemperatureUnit::Celsius(-100.0))
+            .unwrap();
+        assert!((cold - -3.554).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_thermocouple_cold_junction_compensation() {
+        for kind in [ThermocoupleType::K, ThermocoupleType::J, ThermocoupleType::T] {
+            let sensor = Thermocouple::new(kind);
+            let cold_junction = TemperatureUnit::Celsius(25.0);
+            let hot = sensor.voltage_at(TemperatureUnit::Celsius(250.0)).unwrap();
+            let measured = hot - sensor.voltage_at(cold_junction).unwrap();
+
+            let reading = sensor
+                .temperature(measured, cold_junction, TemperatureScale::Celsius)
+                .unwrap();
+            assert!((reading.value() - 250.0).abs() < 0.1, "{:?}: {}", kind, reading);
+        }
+
+        assert_eq!(
+            Thermocouple::new(ThermocoupleType::T).temperature(
+                30.0,
+                TemperatureUnit::Celsius(0.0),
+                TemperatureScale::Celsius
+            ),
+            Err(SensorError::VoltageOutOfRange(30.0))
+        );
+    }
+}
@@ -0,0 +1,353 @@
+// ============================================
+// Kata 1 extension: Temperature Time Series
+// Timestamped readings with interpolation, resampling and rolling statistics
+// ============================================
+
+use super::{TemperatureScale, TemperatureUnit};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum SeriesError {
+    NonPositiveInterval(Duration),
+}
+
+impl fmt::Display for SeriesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SeriesError::NonPositiveInterval(interval) => {
+                write!(f, "Interval must be positive, got {}", interval)
+            }
+        }
+    }
+}
+
+impl Error for SeriesError {}
+
+/// A single reading taken at a point in time
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TemperatureSample {
+    pub timestamp: DateTime<Utc>,
+    pub temperature: TemperatureUnit,
+}
+
+/// Aggregates over the readings in a trailing time window
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RollingStatistics {
+    pub timestamp: DateTime<Utc>,
+    pub min: TemperatureUnit,
+    pub max: TemperatureUnit,
+    pub mean: TemperatureUnit,
+}
+
+/// Stretch between two consecutive samples that exceeds the expected spacing
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Gap {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+impl Gap {
+    pub fn duration(&self) -> Duration {
+        self.end - self.start
+    }
+}
+
+/// Samples kept in timestamp order
+#[derive(Debug, Clone, Default)]
+pub struct TemperatureSeries {
+    samples: Vec<TemperatureSample>,
+}
+
+impl TemperatureSeries {
+    pub fn new() -> Self {
+        TemperatureSeries { samples: Vec::new() }
+    }
+
+    pub fn from_samples(samples: impl IntoIterator<Item = TemperatureSample>) -> Self {
+        let mut samples: Vec<TemperatureSample> = samples.into_iter().collect();
+        samples.sort_by_key(|sample| sample.timestamp);
+        TemperatureSeries { samples }
+    }
+
+    /// Insert a reading, keeping the series ordered by timestamp
+    pub fn push(&mut self, timestamp: DateTime<Utc>, temperature: TemperatureUnit) {
+        let position = self
+            .samples
+            .partition_point(|sample| sample.timestamp <= timestamp);
+        self.samples.insert(position, TemperatureSample { timestamp, temperature });
+    }
+
+    pub fn samples(&self) -> &[TemperatureSample] {
+        &self.samples
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Copy of the series with every reading converted to one scale
+    pub fn normalized(&self, scale: TemperatureScale) -> TemperatureSeries {
+        TemperatureSeries {
+            samples: self
+                .samples
+                .iter()
+                .map(|sample| TemperatureSample {
+                    timestamp: sample.timestamp,
+                    temperature: sample.temperature.convert_to(scale),
+                })
+                .collect(),
+        }
+    }
+
+    /// Linear interpolation at an instant, in the scale of the preceding sample
+    pub fn interpolate(&self, at: DateTime<Utc>) -> Option<TemperatureUnit> {
+        let after = self.samples.partition_point(|sample| sample.timestamp < at);
+        let next = self.samples.get(after)?;
+        if next.timestamp == at {
+            return Some(next.temperature);
+        }
+
+        let previous = self.samples.get(after.checked_sub(1)?)?;
+        let scale = previous.temperature.scale();
+        let start = previous.temperature.value();
+        let end = next.temperature.to_scale(&scale);
+        let fraction = (at - previous.timestamp).as_seconds_f64()
+            / (next.timestamp - previous.timestamp).as_seconds_f64();
+
+        Some(TemperatureUnit::new(scale, start + (end - start) * fraction))
+    }
+
+    /// Interpolated readings at fixed intervals spanning the series, stopping where the
+    /// next timestamp would be out of range
+    pub fn resample(&self, interval: Duration) -> Result<TemperatureSeries, SeriesError> {
+        if interval <= Duration::zero() {
+            return Err(SeriesError::NonPositiveInterval(interval));
+        }
+
+        let (Some(first), Some(last)) = (self.samples.first(), self.samples.last()) else {
+            return Ok(TemperatureSeries::new());
+        };
+
+        let samples = std::iter::successors(Some(first.timestamp), |timestamp| {
+            timestamp.checked_add_signed(interval)
+        })
+        .take_while(|timestamp| *timestamp <= last.timestamp)
+        .filter_map(|timestamp| {
+            self.interpolate(timestamp)
+                .map(|temperature| TemperatureSample { timestamp, temperature })
+        })
+        .collect();
+
+        Ok(TemperatureSeries { samples })
+    }
+
+    /// Min, max and mean over the trailing `window` ending at each sample; a window
+    /// reaching back past the earliest representable time covers the series from its start
+    pub fn rolling(
+        &self,
+        window: Duration,
+        scale: TemperatureScale,
+    ) -> Result<Vec<RollingStatistics>, SeriesError> {
+        if window <= Duration::zero() {
+            return Err(SeriesError::NonPositiveInterval(window));
+        }
+
+        // Each reading is converted once; the window then moves in amortized constant time
+        let values: Vec<f64> = self
+            .samples
+            .iter()
+            .map(|sample| sample.temperature.convert_to(scale).value())
+            .collect();
+        let mut minimum = WindowExtreme::new(|candidate, newer| candidate < newer);
+        let mut maximum = WindowExtreme::new(|candidate, newer| candidate > newer);
+        let mut window_start = 0;
+        let mut sum = 0.0;
+
+        let mut statistics = Vec::with_capacity(values.len());
+        for (index, sample) in self.samples.iter().enumerate() {
+            sum += values[index];
+            minimum.push(&values, index);
+            maximum.push(&values, index);
+            if let Some(cutoff) = sample.timestamp.checked_sub_signed(window) {
+                while self.samples[window_start].timestamp <= cutoff {
+                    sum -= values[window_start];
+                    window_start += 1;
+                }
+            }
+
+            let count = index + 1 - window_start;
+            statistics.push(RollingStatistics {
+                timestamp: sample.timestamp,
+                min: TemperatureUnit::new(scale, values[minimum.front(window_start)]),
+                max: TemperatureUnit::new(scale, values[maximum.front(window_start)]),
+                mean: TemperatureUnit::new(scale, sum / count as f64),
+            });
+        }
+
+        Ok(statistics)
+    }
+
+    /// Spans between consecutive samples longer than `max_interval`
+    pub fn gaps(&self, max_interval: Duration) -> Vec<Gap> {
+        self.samples
+            .windows(2)
+            .filter(|pair| pair[1].timestamp - pair[0].timestamp > max_interval)
+            .map(|pair| Gap {
+                start: pair[0].timestamp,
+                end: pair[1].timestamp,
+            })
+            .collect()
+    }
+}
+
+/// Indices that can still be a sliding window's extreme: the front is the current one, and
+/// each later index is kept only while no newer reading beats it
+struct WindowExtreme {
+    indices: VecDeque<usize>,
+    beats: fn(f64, f64) -> bool,
+}
+
+impl WindowExtreme {
+    fn new(beats: fn(f64, f64) -> bool) -> Self {
+        WindowExtreme { indices: VecDeque::new(), beats }
+    }
+
+    fn push(&mut self, values: &[f64], index: usize) {
+        while let Some(&last) = self.indices.back() {
+            if (self.beats)(values[last], values[index]) {
+                break;
+            }
+            self.indices.pop_back();
+        }
+        self.indices.push_back(index);
+    }
+
+    /// Index of the extreme among readings from `window_start` on; the newest is always kept
+    fn front(&mut self, window_start: usize) -> usize {
+        while self.indices.front().is_some_and(|first| *first < window_start) {
+            self.indices.pop_front();
+        }
+        self.indices[0]
+    }
+}
+
+#[cfg(test)]
+mod series_tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, 0, minute, 0).unwrap()
+    }
+
+    fn sample_series() -> TemperatureSeries {
+        TemperatureSeries::from_samples([
+            TemperatureSample { timestamp: at(10), temperature: TemperatureUnit::Kelvin(293.15) },
+            TemperatureSample { timestamp: at(0), temperature: TemperatureUnit::Celsius(10.0) },
+            TemperatureSample { timestamp: at(30), temperature: TemperatureUnit::Celsius(40.0) },
+        ])
+    }
+
+    #[test]
+    fn test_interpolation_across_scales() {
+        let series = sample_series();
+        let midpoint = series.interpolate(at(5)).unwrap();
+        assert_eq!(midpoint.scale(), TemperatureScale::Celsius);
+        assert!((midpoint.value() - 15.0).abs() < 1e-9);
+        assert_eq!(series.interpolate(at(10)), Some(TemperatureUnit::Celsius(20.0)));
+        assert_eq!(series.interpolate(at(31)), None);
+    }
+
+    #[test]
+    fn test_resample_and_normalize() {
+        let resampled = sample_series()
+            .normalized(TemperatureScale::Celsius)
+            .resample(Duration::minutes(15))
+            .unwrap();
+        let values: Vec<f64> = resampled
+            .samples()
+            .iter()
+            .map(|sample| sample.temperature.value())
+            .collect();
+        assert_eq!(resampled.len(), 3);
+        assert!((values[1] - 25.0).abs() < 1e-9);
+        assert!(sample_series().resample(Duration::zero()).is_err());
+    }
+
+    #[test]
+    fn test_rolling_statistics_and_gaps() {
+        let series = sample_series();
+        let rolling = series
+            .rolling(Duration::minutes(15), TemperatureScale::Celsius)
+            .unwrap();
+        assert_eq!(rolling[1].min, TemperatureUnit::Celsius(10.0));
+        assert!((rolling[1].mean.value() - 15.0).abs() < 1e-9);
+        assert_eq!(rolling[2].max, TemperatureUnit::Celsius(40.0));
+        assert_eq!(rolling[2].min, TemperatureUnit::Celsius(40.0));
+
+        let gaps = series.gaps(Duration::minutes(10));
+        assert_eq!(gaps, vec![Gap { start: at(10), end: at(30) }]);
+        assert_eq!(gaps[0].duration(), Duration::minutes(20));
+    }
+
+    #[test]
+    fn test_push_keeps_timestamp_order() {
+        let mut series = TemperatureSeries::new();
+        series.push(at(20), TemperatureUnit::Celsius(2.0));
+        series.push(at(0), TemperatureUnit::Celsius(0.0));
+        series.push(at(20), TemperatureUnit::Celsius(3.0));
+        series.push(at(10), TemperatureUnit::Kelvin(274.15));
+        let timestamps: Vec<DateTime<Utc>> =
+            series.samples().iter().map(|sample| sample.timestamp).collect();
+        assert_eq!(timestamps, [at(0), at(10), at(20), at(20)]);
+        assert_eq!(series.samples()[3].temperature, TemperatureUnit::Celsius(3.0));
+    }
+
+    #[test]
+    fn test_rolling_matches_recomputing_each_window() {
+        let series = TemperatureSeries::from_samples((0..60).filter(|minute| minute % 7 != 3).map(
+            |minute| TemperatureSample {
+                timestamp: at(minute),
+                temperature: TemperatureUnit::Fahrenheit(((minute * 37) % 23) as f64),
+            },
+        ));
+        let window = Duration::minutes(5);
+        let rolling = series.rolling(window, TemperatureScale::Celsius).unwrap();
+
+        for (statistics, sample) in rolling.iter().zip(series.samples()) {
+            let readings: Vec<TemperatureUnit> = series
+                .samples()
+                .iter()
+                .filter(|other| {
+                    other.timestamp > sample.timestamp - window
+                        && other.timestamp <= sample.timestamp
+                })
+                .map(|other| other.temperature.convert_to(TemperatureScale::Celsius))
+                .collect();
+            let min = readings.iter().copied().reduce(TemperatureUnit::min).unwrap();
+            let max = readings.iter().copied().reduce(TemperatureUnit::max).unwrap();
+            let mean = TemperatureUnit::average(readings, TemperatureScale::Celsius).unwrap();
+            assert_eq!((statistics.min, statistics.max), (min, max));
+            assert!((statistics.mean.value() - mean.value()).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_huge_durations_do_not_overflow() {
+        let series = sample_series();
+        let rolling = series.rolling(Duration::MAX, TemperatureScale::Celsius).unwrap();
+        assert_eq!(rolling[2].min, TemperatureUnit::Celsius(10.0));
+        assert!((rolling[2].mean.value() - 70.0 / 3.0).abs() < 1e-9);
+
+        let resampled = series.resample(Duration::MAX).unwrap();
+        assert_eq!(resampled.len(), 1);
+        assert_eq!(resampled.samples()[0].timestamp, at(0));
+    }
+}
@@ -0,0 +1,126 @@
+// ============================================
+// Kata 1 extension: Compile-Time Typed Temperatures
+// Zero-sized scale markers let the compiler reject unit mix-ups
+// ============================================
+
+use super::{Scale, TemperatureScale, TemperatureUnit};
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Marker for a scale known at compile time
+pub trait TypedScale: Copy {
+    const SCALE: TemperatureScale;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Celsius;
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Fahrenheit;
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Kelvin;
+
+impl TypedScale for Celsius {
+    const SCALE: TemperatureScale = TemperatureScale::Celsius;
+}
+
+impl TypedScale for Fahrenheit {
+    const SCALE: TemperatureScale = TemperatureScale::Fahrenheit;
+}
+
+impl TypedScale for Kelvin {
+    const SCALE: TemperatureScale = TemperatureScale::Kelvin;
+}
+
+/// Temperature whose scale is part of its type
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Temperature<S: TypedScale> {
+    value: f64,
+    scale: PhantomData<S>,
+}
+
+impl<S: TypedScale> Temperature<S> {
+    pub fn new(value: f64) -> Self {
+        Temperature { value, scale: PhantomData }
+    }
+
+    pub fn value(self) -> f64 {
+        self.value
+    }
+
+    /// Convert to another compile-time scale
+    pub fn convert<T: TypedScale>(self) -> Temperature<T> {
+        Temperature::new(S::SCALE.convert_value(self.value, &T::SCALE))
+    }
+
+    /// Erase the scale from the type for runtime-chosen handling
+    pub fn to_unit(self) -> TemperatureUnit {
+        TemperatureUnit::new(S::SCALE, self.value)
+    }
+}
+
+impl<S: TypedScale> From<Temperature<S>> for TemperatureUnit {
+    fn from(temperature: Temperature<S>) -> Self {
+        temperature.to_unit()
+    }
+}
+
+impl<S: TypedScale> From<TemperatureUnit> for Temperature<S> {
+    fn from(unit: TemperatureUnit) -> Self {
+        Temperature::new(unit.to_scale(&S::SCALE))
+    }
+}
+
+macro_rules! impl_typed_conversion {
+    ($source:ty => $target:ty) => {
+        impl From<Temperature<$source>> for Temperature<$target> {
+            fn from(temperature: Temperature<$source>) -> Self {
+                temperature.convert()
+            }
+        }
+    };
+}
+
+impl_typed_conversion!(Celsius => Fahrenheit);
+impl_typed_conversion!(Celsius => Kelvin);
+impl_typed_conversion!(Fahrenheit => Celsius);
+impl_typed_conversion!(Fahrenheit => Kelvin);
+impl_typed_conversion!(Kelvin => Celsius);
+impl_typed_conversion!(Kelvin => Fahrenheit);
+
+impl<S: TypedScale> fmt::Display for Temperature<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.to_unit().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod typed_tests {
+    use super::*;
+
+    fn fever_threshold() -> Temperature<Celsius> {
+        Temperature::new(38.0)
+    }
+
+    #[test]
+    fn test_typed_conversions() {
+        let body: Temperature<Fahrenheit> = Temperature::new(212.0);
+        let celsius: Temperature<Celsius> = body.into();
+        assert_eq!(celsius.value(), 100.0);
+        assert!(celsius > fever_threshold());
+
+        let kelvin = fever_threshold().convert::<Kelvin>();
+        assert!((kelvin.value() - 311.15).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_round_trip_with_runtime_enum() {
+        let unit: TemperatureUnit = Temperature::<Kelvin>::new(273.15).into();
+        assert_eq!(unit.scale(), TemperatureScale::Kelvin);
+
+        let typed = Temperature::<Celsius>::from(TemperatureUnit::Fahrenheit(32.0));
+        assert_eq!(typed.value(), 0.0);
+        assert_eq!(typed.to_string(), "0.00°C");
+    }
+}
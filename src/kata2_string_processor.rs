@@ -4,11 +4,36 @@
 // ============================================
 use std::fmt;
 
+pub mod encoding;
+pub mod frequency;
+pub mod merge;
+pub mod ngram;
+pub mod parallel;
+pub mod readability;
+pub mod streaming;
+pub mod structure;
+pub mod tokenizer;
+pub mod unicode;
+pub mod wc;
+
+use encoding::LineEndingCounts;
+use unicode::CountingMode;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
 pub struct TextStatistics {
     pub word_count: usize,
     pub line_count: usize,
+    /// `\n`, `\r\n` and lone `\r`; unlike `line_count`, an unterminated last line adds nothing
+    pub line_break_count: usize,
     pub character_count: usize,
     pub character_count_no_spaces: usize,
+    // Edges of the text, needed to merge statistics of adjacent chunks
+    starts_with_word: bool,
+    ends_with_word: bool,
+    ends_with_line_break: bool,
+    // A `\r` and `\n` on either side of a chunk boundary form one CRLF line break
+    starts_with_lf: bool,
+    ends_with_cr: bool,
 }
 
 impl TextStatistics {
@@ -17,8 +42,33 @@ impl TextStatistics {
         Self {
             word_count: count_words(text),
             line_count: count_lines(text),
+            line_break_count: count_line_breaks(text),
             character_count: count_characters(text),
             character_count_no_spaces: count_characters_excluding_spaces(text),
+            starts_with_word: starts_with_word(text),
+            ends_with_word: ends_with_word(text),
+            ends_with_line_break: ends_with_line_break(text),
+            starts_with_lf: text.starts_with('\n'),
+            ends_with_cr: text.ends_with('\r'),
+        }
+    }
+
+    /// Create statistics from text, delimiting words and characters per `mode`
+    pub fn from_text_with_mode(text: &str, mode: CountingMode) -> Self {
+        match mode {
+            CountingMode::Standard => Self::from_text(text),
+            CountingMode::Unicode => Self {
+                word_count: unicode::count_unicode_words(text),
+                line_count: count_lines(text),
+                line_break_count: count_line_breaks(text),
+                character_count: unicode::count_graphemes(text),
+                character_count_no_spaces: unicode::count_graphemes_excluding_spaces(text),
+                starts_with_word: starts_with_word(text),
+                ends_with_word: ends_with_word(text),
+                ends_with_line_break: ends_with_line_break(text),
+                starts_with_lf: text.starts_with('\n'),
+                ends_with_cr: text.ends_with('\r'),
+            },
         }
     }
 }
@@ -30,13 +80,15 @@ fn count_words(text: &str) -> usize {
         .count()
 }
 
-/// Count lines
+/// Count lines: each `\n`, `\r\n` or lone `\r` ends one, as does the end of unterminated text
 fn count_lines(text: &str) -> usize {
-    if text.is_empty() {
-        0
-    } else {
-        text.lines().count()
-    }
+    let unterminated_line = !text.is_empty() && !ends_with_line_break(text);
+    count_line_breaks(text) + usize::from(unterminated_line)
+}
+
+/// Count line breaks of every style
+fn count_line_breaks(text: &str) -> usize {
+    LineEndingCounts::from_text(text).total()
 }
 
 /// Count total characters
@@ -51,6 +103,30 @@ fn count_characters_excluding_spaces(text: &str) -> usize {
         .count()
 }
 
+/// Ratio that treats an empty denominator as zero instead of producing NaN
+fn ratio(numerator: usize, denominator: usize) -> f64 {
+    if denominator == 0 {
+        0.0
+    } else {
+        numerator as f64 / denominator as f64
+    }
+}
+
+/// Check whether the text begins inside a word
+fn starts_with_word(text: &str) -> bool {
+    text.chars().next().is_some_and(|ch| !ch.is_whitespace())
+}
+
+/// Check whether the last line of the text is terminated
+fn ends_with_line_break(text: &str) -> bool {
+    text.ends_with(['\n', '\r'])
+}
+
+/// Check whether the text ends inside a word
+fn ends_with_word(text: &str) -> bool {
+    text.chars().next_back().is_some_and(|ch| !ch.is_whitespace())
+}
+
 impl fmt::Display for TextStatistics {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -84,4 +160,12 @@ mod text_statistics_tests {
         assert_eq!(stats.character_count, 11);
         assert_eq!(stats.character_count_no_spaces, 10);
     }
+
+    #[test]
+    fn test_every_line_break_style_ends_a_line() {
+        assert_eq!(TextStatistics::from_text("a\rb\r\nc\nd").line_count, 4);
+        assert_eq!(TextStatistics::from_text("a\r").line_count, 1);
+        assert_eq!(TextStatistics::from_text("\r\n\r\n").line_count, 2);
+        assert_eq!(TextStatistics::from_text("a\rb\r\nc\nd").line_break_count, 3);
+    }
 }
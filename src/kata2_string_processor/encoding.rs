@@ -0,0 +1,236 @@
+// ============================================
+// Kata 2 extension: Line Endings and Encoding
+// Detecting how raw bytes encode text before counting anything in it
+// ============================================
+
+use super::TextStatistics;
+use super::streaming::count_stream;
+use std::fmt;
+use std::io::{self, Read};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineEnding {
+    /// No line breaks at all
+    None,
+    Lf,
+    CrLf,
+    /// Lone `\r`, as on classic Mac OS
+    Cr,
+    Mixed,
+}
+
+/// Number of each kind of line break in a text
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LineEndingCounts {
+    pub lf: usize,
+    pub crlf: usize,
+    pub cr: usize,
+}
+
+impl LineEndingCounts {
+    pub fn from_text(text: &str) -> Self {
+        let mut counts = LineEndingCounts::default();
+        let mut bytes = text.bytes().peekable();
+        while let Some(byte) = bytes.next() {
+            match byte {
+                b'\n' => counts.lf += 1,
+                b'\r' if bytes.peek() == Some(&b'\n') => {
+                    bytes.next();
+                    counts.crlf += 1;
+                }
+                b'\r' => counts.cr += 1,
+                _ => {}
+            }
+        }
+        counts
+    }
+
+    pub fn total(&self) -> usize {
+        self.lf + self.crlf + self.cr
+    }
+
+    /// The single style in use, or `Mixed` when more than one appears
+    pub fn style(&self) -> LineEnding {
+        match (self.lf > 0, self.crlf > 0, self.cr > 0) {
+            (false, false, false) => LineEnding::None,
+            (true, false, false) => LineEnding::Lf,
+            (false, true, false) => LineEnding::CrLf,
+            (false, false, true) => LineEnding::Cr,
+            _ => LineEnding::Mixed,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Encoding {
+    Utf8,
+    Utf8Bom,
+    Utf16Le,
+    Utf16Be,
+    /// Bytes that are not valid UTF-8, decoded as ISO-8859-1
+    Latin1,
+}
+
+impl fmt::Display for Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Encoding::Utf8 => write!(f, "UTF-8"),
+            Encoding::Utf8Bom => write!(f, "UTF-8 with BOM"),
+            Encoding::Utf16Le => write!(f, "UTF-16LE"),
+            Encoding::Utf16Be => write!(f, "UTF-16BE"),
+            Encoding::Latin1 => write!(f, "ISO-8859-1"),
+        }
+    }
+}
+
+/// Identify the encoding from a byte order mark, then by UTF-8 validity
+pub fn detect_encoding(bytes: &[u8]) -> Encoding {
+    if bytes.starts_with(&[0xEF, 0xBB, 0xBF]) {
+        Encoding::Utf8Bom
+    } else if bytes.starts_with(&[0xFF, 0xFE]) {
+        Encoding::Utf16Le
+    } else if bytes.starts_with(&[0xFE, 0xFF]) {
+        Encoding::Utf16Be
+    } else if std::str::from_utf8(bytes).is_ok() {
+        Encoding::Utf8
+    } else {
+        Encoding::Latin1
+    }
+}
+
+/// Decode bytes as detected, without the BOM; invalid sequences become U+FFFD
+pub fn decode(bytes: &[u8]) -> (String, Encoding) {
+    let encoding = detect_encoding(bytes);
+    let text = match encoding {
+        Encoding::Utf8 => String::from_utf8_lossy(bytes).into_owned(),
+        Encoding::Utf8Bom => String::from_utf8_lossy(&bytes[3..]).into_owned(),
+        Encoding::Utf16Le => decode_utf16(&bytes[2..], u16::from_le_bytes),
+        Encoding::Utf16Be => decode_utf16(&bytes[2..], u16::from_be_bytes),
+        Encoding::Latin1 => bytes.iter().map(|byte| char::from(*byte)).collect(),
+    };
+    (text, encoding)
+}
+
+fn decode_utf16(bytes: &[u8], to_unit: fn([u8; 2]) -> u16) -> String {
+    let units = bytes.chunks(2).map(|pair| match pair {
+        [first, second] => to_unit([*first, *second]),
+        _ => 0xFFFD,
+    });
+    char::decode_utf16(units)
+        .map(|unit| unit.unwrap_or(char::REPLACEMENT_CHARACTER))
+        .collect()
+}
+
+/// Text statistics together with what was found about the raw input
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextReport {
+    pub statistics: TextStatistics,
+    pub encoding: Encoding,
+    pub line_endings: LineEndingCounts,
+}
+
+impl TextReport {
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let (text, encoding) = decode(bytes);
+        TextReport {
+            statistics: TextStatistics::from_text(&text),
+            encoding,
+            line_endings: LineEndingCounts::from_text(&text),
+        }
+    }
+
+    /// Same report as `from_bytes`, streaming UTF-8 and Latin-1 input chunk by chunk;
+    /// only input starting with a byte order mark is read into memory whole
+    pub fn from_reader<R: Read>(mut reader: R) -> io::Result<Self> {
+        let mut head = Vec::new();
+        reader.by_ref().take(3).read_to_end(&mut head)?;
+        if !matches!(detect_encoding(&head), Encoding::Utf8 | Encoding::Latin1) {
+            reader.read_to_end(&mut head)?;
+            return Ok(Self::from_bytes(&head));
+        }
+
+        let counts = count_stream(head.as_slice().chain(reader), true)?;
+        let (counter, encoding) = match counts.utf8 {
+            Ok(counter) => (counter, Encoding::Utf8),
+            Err(_) => (counts.latin1.expect("Latin-1 is counted alongside"), Encoding::Latin1),
+        };
+        Ok(TextReport {
+            statistics: counter.finish(),
+            encoding,
+            line_endings: counter.line_endings(),
+        })
+    }
+}
+
+impl fmt::Display for TextReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}, Encoding: {}, Line endings: {:?}",
+            self.statistics,
+            self.encoding,
+            self.line_endings.style()
+        )
+    }
+}
+
+#[cfg(test)]
+mod encoding_tests {
+    use super::*;
+
+    #[test]
+    fn test_line_ending_styles() {
+        assert_eq!(LineEndingCounts::from_text("a\nb\n").style(), LineEnding::Lf);
+        assert_eq!(LineEndingCounts::from_text("a\r\nb\r\n").style(), LineEnding::CrLf);
+        assert_eq!(LineEndingCounts::from_text("a\rb\r").style(), LineEnding::Cr);
+        assert_eq!(LineEndingCounts::from_text("one line").style(), LineEnding::None);
+
+        let mixed = LineEndingCounts::from_text("a\r\nb\nc\rd");
+        assert_eq!(mixed, LineEndingCounts { lf: 1, crlf: 1, cr: 1 });
+        assert_eq!(mixed.style(), LineEnding::Mixed);
+    }
+
+    #[test]
+    fn test_boms_and_latin1_fallback() {
+        assert_eq!(decode(b"\xEF\xBB\xBFhi"), ("hi".to_string(), Encoding::Utf8Bom));
+        assert_eq!(decode(b"\xFF\xFEh\0i\0"), ("hi".to_string(), Encoding::Utf16Le));
+        assert_eq!(decode(b"\xFE\xFF\0h\0i"), ("hi".to_string(), Encoding::Utf16Be));
+        assert_eq!(decode(b"caf\xE9"), ("café".to_string(), Encoding::Latin1));
+        assert_eq!(decode("café".as_bytes()), ("café".to_string(), Encoding::Utf8));
+    }
+
+    #[test]
+    fn test_report_alongside_statistics() {
+        let report = TextReport::from_bytes(b"\xEF\xBB\xBFone two\r\nthree\r\n");
+        assert_eq!(report.statistics.word_count, 3);
+        assert_eq!(report.statistics.line_count, 2);
+        assert_eq!(
+            report.to_string(),
+            "Words: 3, Lines: 2, Characters: 16 (11), Encoding: UTF-8 with BOM, Line endings: CrLf"
+        );
+    }
+
+    #[test]
+    fn test_streamed_report_matches_from_bytes() {
+        let inputs: [&[u8]; 6] = [
+            b"",
+            b"caf\xE9\r\nna\xEFve",
+            "naïve\rcafé\n".as_bytes(),
+            b"\xEF\xBB\xBFone two\r\n",
+            b"\xFF\xFEh\0\n\0",
+            b"\xC3",
+        ];
+        for bytes in inputs {
+            let streamed = TextReport::from_reader(bytes).unwrap();
+            assert_eq!(streamed, TextReport::from_bytes(bytes), "bytes: {:?}", bytes);
+        }
+    }
+
+    #[test]
+    fn test_lone_cr_breaks_lines() {
+        assert_eq!(TextReport::from_bytes(b"a\rb\rc").statistics.line_count, 3);
+        assert_eq!(TextReport::from_bytes(b"a\rb\r").statistics.line_count, 2);
+        assert_eq!(TextReport::from_bytes(b"a\r\nb\nc\rd").statistics.line_count, 4);
+        assert_eq!(TextReport::from_bytes(b"").statistics.line_count, 0);
+    }
+}
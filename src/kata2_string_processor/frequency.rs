@@ -0,0 +1,169 @@
+// ============================================
+// Kata 2 extension: Word Frequency
+// Which words dominate a text, using the same words as `count_words`
+// ============================================
+
+use std::collections::{HashMap, HashSet};
+
+/// A small list of common English function words
+pub const ENGLISH_STOPWORDS: [&str; 32] = [
+    "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "from", "has", "have", "in",
+    "is", "it", "its", "of", "on", "or", "that", "the", "this", "to", "was", "were", "will",
+    "with", "i", "you", "he", "she",
+];
+
+/// Configurable word counting over whitespace-separated words
+#[derive(Debug, Clone)]
+pub struct FrequencyAnalyzer {
+    case_folding: bool,
+    strip_punctuation: bool,
+    /// Kept as given, since normalization depends on settings that may change later
+    stopwords: Vec<String>,
+}
+
+impl FrequencyAnalyzer {
+    /// Case-folding and punctuation stripping on, no stopwords
+    pub fn new() -> Self {
+        FrequencyAnalyzer {
+            case_folding: true,
+            strip_punctuation: true,
+            stopwords: Vec::new(),
+        }
+    }
+
+    pub fn case_folding(mut self, enabled: bool) -> Self {
+        self.case_folding = enabled;
+        self
+    }
+
+    /// Trim punctuation from both ends of each word, keeping inner marks like `don't`
+    pub fn strip_punctuation(mut self, enabled: bool) -> Self {
+        self.strip_punctuation = enabled;
+        self
+    }
+
+    /// Words to leave out; matched after case folding and punctuation stripping
+    pub fn stopwords<'a>(mut self, stopwords: impl IntoIterator<Item = &'a str>) -> Self {
+        self.stopwords = stopwords.into_iter().map(str::to_string).collect();
+        self
+    }
+
+    pub fn analyze(&self, text: &str) -> WordFrequencies {
+        let stopwords: HashSet<String> =
+            self.stopwords.iter().map(|word| self.normalize(word)).collect();
+        text.split_whitespace()
+            .map(|word| self.normalize(word))
+            .filter(|word| !word.is_empty() && !stopwords.contains(word))
+            .collect()
+    }
+
+    pub(crate) fn normalize(&self, word: &str) -> String {
+        let word = if self.strip_punctuation {
+            word.trim_matches(|ch: char| !ch.is_alphanumeric())
+        } else {
+            word
+        };
+        if self.case_folding {
+            word.to_lowercase()
+        } else {
+            word.to_string()
+        }
+    }
+}
+
+impl Default for FrequencyAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Occurrence count of every distinct word
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WordFrequencies {
+    counts: HashMap<String, usize>,
+}
+
+impl WordFrequencies {
+    pub fn count(&self, word: &str) -> usize {
+        self.counts.get(word).copied().unwrap_or(0)
+    }
+
+    /// Number of counted words, including repeats
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    pub fn distinct(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Most frequent words first; ties are broken alphabetically
+    pub fn top(&self, n: usize) -> Vec<(&str, usize)> {
+        let mut ranked: Vec<(&str, usize)> = self
+            .counts
+            .iter()
+            .map(|(word, count)| (word.as_str(), *count))
+            .collect();
+        ranked.sort_unstable_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        ranked.truncate(n);
+        ranked
+    }
+}
+
+impl FromIterator<String> for WordFrequencies {
+    fn from_iter<I: IntoIterator<Item = String>>(terms: I) -> Self {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for term in terms {
+            *counts.entry(term).or_insert(0) += 1;
+        }
+        WordFrequencies { counts }
+    }
+}
+
+#[cfg(test)]
+mod frequency_tests {
+    use super::*;
+
+    const TEXT: &str = "The cat sat. The cat ran! A dog? The dog, the end... don't";
+
+    #[test]
+    fn test_case_folding_and_punctuation() {
+        let frequencies = FrequencyAnalyzer::new().analyze(TEXT);
+        assert_eq!(frequencies.count("the"), 4);
+        assert_eq!(frequencies.count("dog"), 2);
+        assert_eq!(frequencies.count("don't"), 1);
+        assert_eq!(frequencies.total(), 13);
+
+        let raw = FrequencyAnalyzer::new()
+            .case_folding(false)
+            .strip_punctuation(false)
+            .analyze(TEXT);
+        assert_eq!(raw.count("The"), 3);
+        assert_eq!(raw.count("dog,"), 1);
+    }
+
+    #[test]
+    fn test_stopwords_are_excluded() {
+        let frequencies = FrequencyAnalyzer::new()
+            .stopwords(ENGLISH_STOPWORDS)
+            .analyze(TEXT);
+        assert_eq!(frequencies.count("the"), 0);
+        assert_eq!(frequencies.count("a"), 0);
+        assert_eq!(frequencies.distinct(), 6);
+
+        let case_sensitive = FrequencyAnalyzer::new()
+            .stopwords(["The"])
+            .case_folding(false)
+            .analyze(TEXT);
+        assert_eq!(case_sensitive.count("The"), 0);
+        assert_eq!(case_sensitive.count("the"), 1);
+    }
+
+    #[test]
+    fn test_top_n_breaks_ties_alphabetically() {
+        let frequencies = FrequencyAnalyzer::new().stopwords(["The"]).analyze(TEXT);
+        assert_eq!(frequencies.top(3), [("cat", 2), ("dog", 2), ("a", 1)]);
+        assert_eq!(frequencies.top(100).len(), frequencies.distinct());
+        assert!(FrequencyAnalyzer::new().analyze("").top(5).is_empty());
+    }
+}
@@ -0,0 +1,124 @@
+// ============================================
+// Kata 2 extension: Mergeable Statistics
+// Combining per-chunk or per-file results into totals
+// ============================================
+
+use super::TextStatistics;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign};
+
+impl TextStatistics {
+    /// Statistics of `self`'s text directly followed by `next`'s text, as for chunks of one
+    /// stream: a word, line or CRLF cut at the boundary is counted once (words are
+    /// whitespace-delimited, so statistics from `from_text_with_tokenizer` cannot be joined)
+    pub fn followed_by(self, next: TextStatistics) -> TextStatistics {
+        if next.character_count == 0 {
+            return self;
+        }
+        if self.character_count == 0 {
+            return next;
+        }
+
+        let split_word = self.ends_with_word && next.starts_with_word;
+        let split_line = !self.ends_with_line_break;
+        let split_crlf = self.ends_with_cr && next.starts_with_lf;
+        TextStatistics {
+            word_count: self.word_count + next.word_count - usize::from(split_word),
+            line_count: self.line_count + next.line_count
+                - usize::from(split_line)
+                - usize::from(split_crlf),
+            line_break_count: self.line_break_count + next.line_break_count
+                - usize::from(split_crlf),
+            ..self + next
+        }
+    }
+}
+
+impl Add for TextStatistics {
+    type Output = TextStatistics;
+
+    /// Totals over two independent documents, as `wc` adds up files
+    fn add(self, other: TextStatistics) -> TextStatistics {
+        if other.character_count == 0 {
+            return self;
+        }
+        if self.character_count == 0 {
+            return other;
+        }
+
+        TextStatistics {
+            word_count: self.word_count + other.word_count,
+            line_count: self.line_count + other.line_count,
+            line_break_count: self.line_break_count + other.line_break_count,
+            character_count: self.character_count + other.character_count,
+            character_count_no_spaces: self.character_count_no_spaces
+                + other.character_count_no_spaces,
+            starts_with_word: self.starts_with_word,
+            ends_with_word: other.ends_with_word,
+            ends_with_line_break: other.ends_with_line_break,
+            starts_with_lf: self.starts_with_lf,
+            ends_with_cr: other.ends_with_cr,
+        }
+    }
+}
+
+impl AddAssign for TextStatistics {
+    fn add_assign(&mut self, other: TextStatistics) {
+        *self = *self + other;
+    }
+}
+
+impl Sum for TextStatistics {
+    fn sum<I: Iterator<Item = TextStatistics>>(iter: I) -> Self {
+        iter.fold(TextStatistics::default(), Add::add)
+    }
+}
+
+impl<'a> Sum<&'a TextStatistics> for TextStatistics {
+    fn sum<I: Iterator<Item = &'a TextStatistics>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+#[cfg(test)]
+mod merge_tests {
+    use super::*;
+
+    #[test]
+    fn test_chunks_followed_by_equal_whole() {
+        let text = "first line\nsecond li\nne ends\r\nhere\rold mac\r\n\nlast";
+        for split in (0..=text.len()).filter(|index| text.is_char_boundary(*index)) {
+            let (head, tail) = text.split_at(split);
+            let head = TextStatistics::from_text(head);
+            let merged = head.followed_by(TextStatistics::from_text(tail));
+            assert_eq!(merged, TextStatistics::from_text(text), "split at {}", split);
+        }
+    }
+
+    #[test]
+    fn test_only_chunks_join_an_unterminated_line() {
+        let head = TextStatistics::from_text("half a ");
+        let joined = head.followed_by(TextStatistics::from_text("line\nnext\n"));
+        assert_eq!(joined.line_count, 2);
+        assert_eq!(joined.word_count, 4);
+
+        let after_cr = TextStatistics::from_text("a\r").followed_by(TextStatistics::from_text("b"));
+        assert_eq!(after_cr.line_count, 2);
+
+        let mut separate = TextStatistics::from_text("a");
+        separate += TextStatistics::from_text("b");
+        assert_eq!((separate.word_count, separate.line_count), (2, 2));
+    }
+
+    #[test]
+    fn test_grand_total_over_files() {
+        let files = ["alpha beta", "", "gamma\ndelta\n"];
+        let per_file: Vec<TextStatistics> =
+            files.iter().map(|text| TextStatistics::from_text(text)).collect();
+        let total: TextStatistics = per_file.iter().sum();
+        assert_eq!(total.word_count, 4);
+        assert_eq!(total.line_count, 3);
+        assert_eq!(total.line_break_count, 2);
+        assert_eq!(total.character_count, 22);
+    }
+}
@@ -0,0 +1,169 @@
+// ============================================
+// Kata 2 extension: N-grams and Collocations
+// Sliding windows over words or characters, and which word pairs belong together
+// ============================================
+
+use super::frequency::{FrequencyAnalyzer, WordFrequencies};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NgramUnit {
+    /// Normalized words, joined by a single space
+    Words,
+    Characters,
+}
+
+/// Extracts every n-gram from a sliding window of size `n`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NgramExtractor {
+    n: usize,
+    unit: NgramUnit,
+    case_folding: bool,
+}
+
+impl NgramExtractor {
+    pub fn words(n: usize) -> Self {
+        NgramExtractor {
+            n,
+            unit: NgramUnit::Words,
+            case_folding: true,
+        }
+    }
+
+    pub fn characters(n: usize) -> Self {
+        NgramExtractor {
+            n,
+            unit: NgramUnit::Characters,
+            case_folding: true,
+        }
+    }
+
+    pub fn case_folding(mut self, enabled: bool) -> Self {
+        self.case_folding = enabled;
+        self
+    }
+
+    /// All n-grams in order of appearance; empty when `n` is zero or exceeds the input
+    pub fn extract(&self, text: &str) -> Vec<String> {
+        if self.n == 0 {
+            return Vec::new();
+        }
+        match self.unit {
+            NgramUnit::Words => {
+                let words = normalized_words(text, self.case_folding);
+                words.windows(self.n).map(|window| window.join(" ")).collect()
+            }
+            NgramUnit::Characters => {
+                let text = if self.case_folding {
+                    text.to_lowercase()
+                } else {
+                    text.to_string()
+                };
+                let chars: Vec<char> = text.chars().collect();
+                chars.windows(self.n).map(|window| window.iter().collect()).collect()
+            }
+        }
+    }
+
+    pub fn frequencies(&self, text: &str) -> WordFrequencies {
+        self.extract(text).into_iter().collect()
+    }
+}
+
+/// A word pair scored by pointwise mutual information
+#[derive(Debug, Clone, PartialEq)]
+pub struct Collocation {
+    pub first: String,
+    pub second: String,
+    pub count: usize,
+    /// `log2(p(first second) / (p(first) * p(second)))`
+    pub pmi: f64,
+}
+
+/// Bigrams seen at least `min_count` times, highest PMI first, ties broken alphabetically.
+/// PMI overrates rare pairs, so a `min_count` above one is usually wanted.
+pub fn collocations(text: &str, min_count: usize) -> Vec<Collocation> {
+    let words = normalized_words(text, true);
+    if words.len() < 2 {
+        return Vec::new();
+    }
+
+    let mut word_counts: HashMap<&str, usize> = HashMap::new();
+    for word in &words {
+        *word_counts.entry(word.as_str()).or_insert(0) += 1;
+    }
+    let mut bigram_counts: HashMap<(&str, &str), usize> = HashMap::new();
+    for pair in words.windows(2) {
+        *bigram_counts.entry((pair[0].as_str(), pair[1].as_str())).or_insert(0) += 1;
+    }
+
+    let total_words = words.len() as f64;
+    let total_bigrams = (words.len() - 1) as f64;
+    let probability = |word: &str| word_counts[word] as f64 / total_words;
+
+    let mut scored: Vec<Collocation> = bigram_counts
+        .into_iter()
+        .filter(|(_, count)| *count >= min_count)
+        .map(|((first, second), count)| {
+            let joint = count as f64 / total_bigrams;
+            Collocation {
+                first: first.to_string(),
+                second: second.to_string(),
+                count,
+                pmi: (joint / (probability(first) * probability(second))).log2(),
+            }
+        })
+        .collect();
+    scored.sort_by(|a, b| {
+        b.pmi
+            .total_cmp(&a.pmi)
+            .then_with(|| (&a.first, &a.second).cmp(&(&b.first, &b.second)))
+    });
+    scored
+}
+
+/// Words as the frequency analysis sees them: punctuation trimmed, optionally lowercased
+fn normalized_words(text: &str, case_folding: bool) -> Vec<String> {
+    let analyzer = FrequencyAnalyzer::new().case_folding(case_folding);
+    text.split_whitespace()
+        .map(|word| analyzer.normalize(word))
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod ngram_tests {
+    use super::*;
+
+    #[test]
+    fn test_word_ngrams() {
+        let trigrams = NgramExtractor::words(3).extract("The quick, brown fox jumps.");
+        assert_eq!(trigrams, ["the quick brown", "quick brown fox", "brown fox jumps"]);
+        assert!(NgramExtractor::words(6).extract("too short").is_empty());
+        assert!(NgramExtractor::words(0).extract("anything").is_empty());
+    }
+
+    #[test]
+    fn test_character_ngram_frequencies() {
+        let bigrams = NgramExtractor::characters(2).extract("Naïve");
+        assert_eq!(bigrams, ["na", "aï", "ïv", "ve"]);
+
+        let frequencies = NgramExtractor::characters(2).case_folding(false).frequencies("abABab");
+        assert_eq!(frequencies.count("ab"), 2);
+        assert_eq!(frequencies.count("AB"), 1);
+        assert_eq!(frequencies.total(), 5);
+    }
+
+    #[test]
+    fn test_bigram_pmi() {
+        let scored = collocations("New York is big. New York is busy.", 2);
+        let pairs: Vec<(&str, &str)> = scored
+            .iter()
+            .map(|collocation| (collocation.first.as_str(), collocation.second.as_str()))
+            .collect();
+        assert_eq!(pairs, [("new", "york"), ("york", "is")]);
+        assert_eq!(scored[0].count, 2);
+        assert!((scored[0].pmi - (32.0_f64 / 7.0).log2()).abs() < 1e-9);
+        assert!(collocations("alone", 1).is_empty());
+    }
+}
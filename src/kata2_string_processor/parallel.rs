@@ -0,0 +1,125 @@
+// ============================================
+// Kata 2 extension: Single-Pass and Parallel Statistics
+// One scan instead of four, then the same scan split across threads
+// ============================================
+
+use super::TextStatistics;
+use super::streaming::StatisticsCounter;
+use std::thread;
+
+impl TextStatistics {
+    /// Create statistics from text in a single scan, with the same results as `from_text`
+    pub fn from_text_single_pass(text: &str) -> Self {
+        let mut counter = StatisticsCounter::default();
+        counter.feed(text);
+        counter.finish()
+    }
+
+    /// Create statistics by scanning roughly equal slices of text on `threads` threads
+    pub fn from_text_parallel(text: &str, threads: usize) -> Self {
+        let slices = split_at_char_boundaries(text, threads.max(1));
+        thread::scope(|scope| {
+            let handles: Vec<_> = slices
+                .into_iter()
+                .map(|slice| scope.spawn(move || Self::from_text_single_pass(slice)))
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().expect("counting thread panicked"))
+                .fold(TextStatistics::default(), TextStatistics::followed_by)
+        })
+    }
+}
+
+/// Split into at most `parts` slices without cutting through a UTF-8 sequence
+fn split_at_char_boundaries(text: &str, parts: usize) -> Vec<&str> {
+    let target_len = text.len().div_ceil(parts).max(1);
+    let mut slices = Vec::with_capacity(parts);
+    let mut rest = text;
+
+    while !rest.is_empty() {
+        let mut end = target_len.min(rest.len());
+        while !rest.is_char_boundary(end) {
+            end += 1;
+        }
+        let (slice, remainder) = rest.split_at(end);
+        slices.push(slice);
+        rest = remainder;
+    }
+    slices
+}
+
+#[cfg(test)]
+mod parallel_tests {
+    use super::*;
+
+    const SAMPLES: [&str; 6] = [
+        "",
+        "\n",
+        "Hello world",
+        "one\ntwo\n",
+        "crlf\r\nand\rcr\r\n",
+        "  naïve  café 👍🏽\n\nend",
+    ];
+
+    #[test]
+    fn test_single_pass_matches_from_text() {
+        for text in SAMPLES {
+            let single_pass = TextStatistics::from_text_single_pass(text);
+            assert_eq!(single_pass, TextStatistics::from_text(text));
+        }
+    }
+
+    #[test]
+    fn test_parallel_matches_from_text_for_any_split() {
+        for text in SAMPLES {
+            for threads in [0, 1, 2, 3, 7, 64] {
+                assert_eq!(
+                    TextStatistics::from_text_parallel(text, threads),
+                    TextStatistics::from_text(text),
+                    "text: {:?}, threads: {}",
+                    text,
+                    threads
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_split_respects_char_boundaries() {
+        let slices = split_at_char_boundaries("ééé", 4);
+        assert_eq!(slices, ["é", "é", "é"]);
+        assert_eq!(split_at_char_boundaries("", 4), Vec::<&str>::new());
+    }
+}
+
+#[cfg(test)]
+mod parallel_benches {
+    extern crate test;
+
+    use super::*;
+    use test::Bencher;
+
+    fn corpus() -> String {
+        "The quick brown fox jumps over the lazy dog. Ünïcödé täxt, too!\n".repeat(64 * 1024)
+    }
+
+    #[bench]
+    fn bench_four_passes(bencher: &mut Bencher) {
+        let text = corpus();
+        bencher.iter(|| TextStatistics::from_text(test::black_box(&text)));
+    }
+
+    #[bench]
+    fn bench_single_pass(bencher: &mut Bencher) {
+        let text = corpus();
+        bencher.iter(|| TextStatistics::from_text_single_pass(test::black_box(&text)));
+    }
+
+    #[bench]
+    fn bench_parallel(bencher: &mut Bencher) {
+        let text = corpus();
+        let threads = thread::available_parallelism().map_or(4, |threads| threads.get());
+        bencher.iter(|| TextStatistics::from_text_parallel(test::black_box(&text), threads));
+    }
+}
@@ -0,0 +1,146 @@
+// ============================================
+// Kata 2 extension: Readability Scores
+// Sentence and syllable counts feeding the classic readability formulas
+// ============================================
+
+use super::ratio;
+use super::structure::count_sentences;
+use std::fmt;
+
+/// Counts behind the readability formulas, with the scores derived from them
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Readability {
+    pub sentence_count: usize,
+    pub word_count: usize,
+    pub syllable_count: usize,
+    /// Words of three or more syllables
+    pub polysyllable_count: usize,
+}
+
+impl Readability {
+    /// Analyze text; words are whitespace-separated and must contain a letter
+    pub fn from_text(text: &str) -> Self {
+        let syllables: Vec<usize> = text
+            .split_whitespace()
+            .filter(|word| word.chars().any(char::is_alphabetic))
+            .map(count_syllables)
+            .collect();
+
+        Self {
+            sentence_count: count_sentences(text),
+            word_count: syllables.len(),
+            syllable_count: syllables.iter().sum(),
+            polysyllable_count: syllables.iter().filter(|count| **count >= 3).count(),
+        }
+    }
+
+    /// 0–100, higher is easier
+    pub fn flesch_reading_ease(&self) -> f64 {
+        206.835 - 1.015 * self.words_per_sentence() - 84.6 * self.syllables_per_word()
+    }
+
+    /// U.S. school grade level
+    pub fn flesch_kincaid_grade(&self) -> f64 {
+        0.39 * self.words_per_sentence() + 11.8 * self.syllables_per_word() - 15.59
+    }
+
+    /// Years of formal education needed on first reading
+    pub fn gunning_fog(&self) -> f64 {
+        let complex_ratio = ratio(self.polysyllable_count, self.word_count);
+        0.4 * (self.words_per_sentence() + 100.0 * complex_ratio)
+    }
+
+    /// Grade level, normalized to a 30-sentence sample
+    pub fn smog(&self) -> f64 {
+        let polysyllables_per_30 = ratio(self.polysyllable_count * 30, self.sentence_count);
+        1.0430 * polysyllables_per_30.sqrt() + 3.1291
+    }
+
+    fn words_per_sentence(&self) -> f64 {
+        ratio(self.word_count, self.sentence_count)
+    }
+
+    fn syllables_per_word(&self) -> f64 {
+        ratio(self.syllable_count, self.word_count)
+    }
+}
+
+/// Estimate syllables from vowel groups, discounting a silent final `e`
+fn count_syllables(word: &str) -> usize {
+    let letters: Vec<char> = word
+        .chars()
+        .filter(|ch| ch.is_alphabetic())
+        .flat_map(char::to_lowercase)
+        .collect();
+    let is_vowel = |ch: &char| matches!(ch, 'a' | 'e' | 'i' | 'o' | 'u' | 'y');
+
+    let mut groups = 0;
+    let mut previous_was_vowel = false;
+    for ch in &letters {
+        let vowel = is_vowel(ch);
+        if vowel && !previous_was_vowel {
+            groups += 1;
+        }
+        previous_was_vowel = vowel;
+    }
+
+    let silent_e = letters.ends_with(&['e']) && !letters.ends_with(&['l', 'e']);
+    if silent_e && groups > 1 {
+        groups -= 1;
+    }
+    groups.max(1)
+}
+
+impl fmt::Display for Readability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Sentences: {}, Syllables: {}, Flesch Reading Ease: {:.2}, \
+             Flesch-Kincaid Grade: {:.2}, Gunning Fog: {:.2}, SMOG: {:.2}",
+            self.sentence_count,
+            self.syllable_count,
+            self.flesch_reading_ease(),
+            self.flesch_kincaid_grade(),
+            self.gunning_fog(),
+            self.smog()
+        )
+    }
+}
+
+#[cfg(test)]
+mod readability_tests {
+    use super::*;
+
+    #[test]
+    fn test_syllable_and_sentence_counts() {
+        let syllables: Vec<usize> = ["the", "make", "table", "queue", "readability", "Rhythm!"]
+            .into_iter()
+            .map(count_syllables)
+            .collect();
+        assert_eq!(syllables, [1, 1, 2, 1, 5, 1]);
+        assert_eq!(count_sentences("Wait... what?! Yes. And then"), 4);
+        assert_eq!(count_sentences("  ...  "), 0);
+    }
+
+    #[test]
+    fn test_simple_sentence_scores() {
+        let readability = Readability::from_text("The cat sat on the mat.");
+        assert_eq!(readability.word_count, 6);
+        assert!((readability.flesch_reading_ease() - 116.145).abs() < 1e-9);
+        assert!((readability.flesch_kincaid_grade() + 1.45).abs() < 1e-9);
+        assert!((readability.gunning_fog() - 2.4).abs() < 1e-9);
+        assert!((readability.smog() - 3.1291).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_report_and_empty_text() {
+        let report = Readability::from_text("Readability formulas penalize polysyllabic words.");
+        assert_eq!(report.polysyllable_count, 4);
+        assert_eq!(
+            report.to_string(),
+            "Sentences: 1, Syllables: 17, Flesch Reading Ease: -85.88, \
+             Flesch-Kincaid Grade: 26.48, Gunning Fog: 34.00, SMOG: 14.55"
+        );
+        assert_eq!(Readability::from_text("").flesch_kincaid_grade(), -15.59);
+    }
+}
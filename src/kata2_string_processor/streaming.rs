@@ -0,0 +1,207 @@
+// ============================================
+// Kata 2 extension: Streaming Statistics
+// Counting readers chunk by chunk without holding the whole text in memory
+// ============================================
+
+use super::TextStatistics;
+use super::encoding::LineEndingCounts;
+use std::io::{self, Read};
+
+pub(crate) const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Running counts that give the same results as `from_text` however the text is split
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct StatisticsCounter {
+    words: usize,
+    line_endings: LineEndingCounts,
+    characters: usize,
+    characters_no_spaces: usize,
+    in_word: bool,
+    starts_with_word: bool,
+    starts_with_lf: bool,
+    ends_with_cr: bool,
+    ends_with_line_break: bool,
+}
+
+impl StatisticsCounter {
+    pub(crate) fn feed(&mut self, text: &str) {
+        self.feed_chars(text.chars());
+    }
+
+    /// Bytes decoded as ISO-8859-1, where every byte is the code point of the same value
+    pub(crate) fn feed_latin1(&mut self, bytes: &[u8]) {
+        self.feed_chars(bytes.iter().map(|byte| char::from(*byte)));
+    }
+
+    fn feed_chars(&mut self, chars: impl Iterator<Item = char>) {
+        for ch in chars {
+            if self.characters == 0 {
+                self.starts_with_word = !ch.is_whitespace();
+                self.starts_with_lf = ch == '\n';
+            }
+            self.characters += 1;
+            if ch.is_whitespace() {
+                self.in_word = false;
+            } else {
+                self.characters_no_spaces += 1;
+                if !self.in_word {
+                    self.words += 1;
+                    self.in_word = true;
+                }
+            }
+            match ch {
+                '\n' if self.ends_with_cr => {
+                    self.line_endings.cr -= 1;
+                    self.line_endings.crlf += 1;
+                }
+                '\n' => self.line_endings.lf += 1,
+                '\r' => self.line_endings.cr += 1,
+                _ => {}
+            }
+            self.ends_with_cr = ch == '\r';
+            self.ends_with_line_break = matches!(ch, '\n' | '\r');
+        }
+    }
+
+    pub(crate) fn line_endings(&self) -> LineEndingCounts {
+        self.line_endings
+    }
+
+    /// A final line without a terminating line break still counts, as in `from_text`
+    pub(crate) fn finish(self) -> TextStatistics {
+        let unterminated_line = self.characters > 0 && !self.ends_with_line_break;
+        TextStatistics {
+            word_count: self.words,
+            line_count: self.line_endings.total() + usize::from(unterminated_line),
+            line_break_count: self.line_endings.total(),
+            character_count: self.characters,
+            character_count_no_spaces: self.characters_no_spaces,
+            starts_with_word: self.starts_with_word,
+            ends_with_word: self.in_word,
+            ends_with_line_break: self.ends_with_line_break,
+            starts_with_lf: self.starts_with_lf,
+            ends_with_cr: self.ends_with_cr,
+        }
+    }
+}
+
+impl TextStatistics {
+    /// Create statistics from any reader, decoding UTF-8 across chunk boundaries
+    pub fn from_reader<R: Read>(reader: R) -> io::Result<Self> {
+        Ok(count_stream(reader, false)?.utf8?.finish())
+    }
+}
+
+/// Counts of a whole stream: as UTF-8 or the reason it is not UTF-8, and, when asked for,
+/// as Latin-1, which decodes any bytes and so needs no lookahead to fall back on
+pub(crate) struct StreamCounts {
+    pub(crate) utf8: io::Result<StatisticsCounter>,
+    pub(crate) latin1: Option<StatisticsCounter>,
+}
+
+/// Read `reader` to the end in fixed-size chunks, holding at most one chunk in memory
+pub(crate) fn count_stream<R: Read>(mut reader: R, latin1: bool) -> io::Result<StreamCounts> {
+    let mut utf8 = Ok(StatisticsCounter::default());
+    let mut latin1 = latin1.then(StatisticsCounter::default);
+    let mut buffer = vec![0; CHUNK_SIZE];
+    let mut filled = 0;
+
+    loop {
+        let read = match reader.read(&mut buffer[filled..]) {
+            Ok(read) => read,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        };
+        if read == 0 {
+            break;
+        }
+        if let Some(counter) = &mut latin1 {
+            counter.feed_latin1(&buffer[filled..filled + read]);
+        }
+        filled += read;
+
+        if let Ok(counter) = &mut utf8 {
+            match std::str::from_utf8(&buffer[..filled]) {
+                Ok(text) => {
+                    counter.feed(text);
+                    filled = 0;
+                }
+                Err(error) if error.error_len().is_none() => {
+                    let valid = error.valid_up_to();
+                    let text =
+                        std::str::from_utf8(&buffer[..valid]).expect("prefix was just validated");
+                    counter.feed(text);
+                    // Keep the start of a multi-byte sequence for the next read
+                    buffer.copy_within(valid..filled, 0);
+                    filled -= valid;
+                }
+                Err(error) => utf8 = Err(io::Error::new(io::ErrorKind::InvalidData, error)),
+            }
+        }
+        if utf8.is_err() {
+            if latin1.is_none() {
+                break;
+            }
+            filled = 0;
+        }
+    }
+
+    if filled > 0 && utf8.is_ok() {
+        utf8 = Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "stream ended in the middle of a UTF-8 sequence",
+        ));
+    }
+    Ok(StreamCounts { utf8, latin1 })
+}
+
+#[cfg(test)]
+mod streaming_tests {
+    use super::*;
+
+    /// Hands out one byte per read to split every word, line and code point
+    struct OneByteReader<'a>(&'a [u8]);
+
+    impl Read for OneByteReader<'_> {
+        fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+            let Some((first, rest)) = self.0.split_first() else {
+                return Ok(0);
+            };
+            buffer[0] = *first;
+            self.0 = rest;
+            Ok(1)
+        }
+    }
+
+    #[test]
+    fn test_matches_from_text_across_boundaries() {
+        let texts = [
+            "",
+            "\n",
+            "Hello world",
+            "one\ntwo\n",
+            "a\r\nb",
+            "a\rb\r\r\nc\r",
+            "  naïve  café 👍🏽\n\nend",
+        ];
+        for text in texts {
+            let streamed = TextStatistics::from_reader(OneByteReader(text.as_bytes())).unwrap();
+            assert_eq!(streamed, TextStatistics::from_text(text), "text: {:?}", text);
+        }
+    }
+
+    #[test]
+    fn test_large_input_spanning_chunks() {
+        let text = "ünïcödé words\n".repeat(CHUNK_SIZE / 7);
+        let streamed = TextStatistics::from_reader(text.as_bytes()).unwrap();
+        assert_eq!(streamed, TextStatistics::from_text(&text));
+    }
+
+    #[test]
+    fn test_rejects_invalid_utf8() {
+        let invalid = TextStatistics::from_reader(&b"ok \xff"[..]).unwrap_err();
+        assert_eq!(invalid.kind(), io::ErrorKind::InvalidData);
+        let truncated = TextStatistics::from_reader(&"é".as_bytes()[..1]).unwrap_err();
+        assert_eq!(truncated.kind(), io::ErrorKind::InvalidData);
+    }
+}
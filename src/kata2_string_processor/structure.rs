@@ -0,0 +1,198 @@
+// ============================================
+// Kata 2 extension: Line and Sentence Structure
+// Lengths, blank lines, sentences and paragraphs for linting documents
+// ============================================
+
+use super::ratio;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Words whose trailing period does not end a sentence, compared lowercase without dots
+const ABBREVIATIONS: [&str; 13] = [
+    "mr", "mrs", "ms", "dr", "prof", "sr", "jr", "st", "vs", "etc", "inc", "fig", "approx",
+];
+
+/// Shape of a text: how long its lines are and how it divides into sentences and paragraphs
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructureStatistics {
+    /// Length in characters of every line, as split by `str::lines`
+    line_lengths: Vec<usize>,
+    pub blank_line_count: usize,
+    pub sentence_count: usize,
+    pub paragraph_count: usize,
+    pub word_count: usize,
+}
+
+impl StructureStatistics {
+    pub fn from_text(text: &str) -> Self {
+        Self {
+            line_lengths: text.lines().map(|line| line.chars().count()).collect(),
+            blank_line_count: text.lines().filter(|line| line.trim().is_empty()).count(),
+            sentence_count: count_sentences(text),
+            paragraph_count: count_paragraphs(text),
+            word_count: text.split_whitespace().count(),
+        }
+    }
+
+    pub fn longest_line(&self) -> Option<usize> {
+        self.line_lengths.iter().copied().max()
+    }
+
+    pub fn shortest_line(&self) -> Option<usize> {
+        self.line_lengths.iter().copied().min()
+    }
+
+    pub fn average_line_length(&self) -> f64 {
+        ratio(self.line_lengths.iter().sum(), self.line_lengths.len())
+    }
+
+    pub fn average_words_per_sentence(&self) -> f64 {
+        ratio(self.word_count, self.sentence_count)
+    }
+
+    /// Number of lines per length bucket, keyed by the bucket's lower bound
+    pub fn line_length_histogram(&self, bucket_width: usize) -> BTreeMap<usize, usize> {
+        let bucket_width = bucket_width.max(1);
+        let mut histogram = BTreeMap::new();
+        for length in &self.line_lengths {
+            *histogram.entry(length / bucket_width * bucket_width).or_insert(0) += 1;
+        }
+        histogram
+    }
+
+    /// 1-based numbers of lines longer than `limit` characters
+    pub fn lines_longer_than(&self, limit: usize) -> Vec<usize> {
+        self.line_lengths
+            .iter()
+            .enumerate()
+            .filter(|(_, length)| **length > limit)
+            .map(|(index, _)| index + 1)
+            .collect()
+    }
+}
+
+/// Count sentences ending in `.`, `!` or `?` (or the end of input), skipping abbreviations
+/// such as `Dr.`, dotted ones such as `e.g.`, `No.` before a number and initials such as `J.`
+pub(crate) fn count_sentences(text: &str) -> usize {
+    let mut sentences = 0;
+    let mut has_content = false;
+    let mut words = text.split_whitespace().peekable();
+    while let Some(word) = words.next() {
+        has_content |= word.chars().any(char::is_alphanumeric);
+        if has_content && ends_sentence(word, words.peek().copied()) {
+            sentences += 1;
+            has_content = false;
+        }
+    }
+    sentences + usize::from(has_content)
+}
+
+fn ends_sentence(word: &str, next: Option<&str>) -> bool {
+    let word = word.trim_end_matches(['"', '\'', ')', ']', '”', '’']);
+    if word.ends_with(['!', '?']) {
+        return true;
+    }
+    if !word.ends_with('.') {
+        return false;
+    }
+
+    let word = word.trim_start_matches(['"', '\'', '(', '[', '“', '‘']);
+    let dotted = word.trim_end_matches('.').contains('.');
+    let stem: String = word
+        .chars()
+        .filter(|ch| *ch != '.')
+        .flat_map(char::to_lowercase)
+        .collect();
+    let next_starts_with = |test: fn(&char) -> bool| {
+        next.and_then(|next| next.chars().next()).is_some_and(|ch| test(&ch))
+    };
+    let numbered = stem == "no" && next_starts_with(char::is_ascii_digit);
+    let mut chars = word.chars();
+    let is_initial = matches!(
+        (chars.next(), chars.next(), chars.next()),
+        (Some(first), Some('.'), None) if first.is_uppercase() && first != 'I'
+    ) && next_starts_with(|ch| ch.is_uppercase());
+    !(ABBREVIATIONS.contains(&stem.as_str()) || dotted || numbered || is_initial)
+}
+
+/// Count runs of non-blank lines separated by blank lines
+fn count_paragraphs(text: &str) -> usize {
+    let mut paragraphs = 0;
+    let mut in_paragraph = false;
+    for line in text.lines() {
+        let blank = line.trim().is_empty();
+        if !blank && !in_paragraph {
+            paragraphs += 1;
+        }
+        in_paragraph = !blank;
+    }
+    paragraphs
+}
+
+impl fmt::Display for StructureStatistics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Lines: {} (longest {}, shortest {}, average {:.2}, blank {}), \
+             Sentences: {} ({:.2} words each), Paragraphs: {}",
+            self.line_lengths.len(),
+            self.longest_line().unwrap_or(0),
+            self.shortest_line().unwrap_or(0),
+            self.average_line_length(),
+            self.blank_line_count,
+            self.sentence_count,
+            self.average_words_per_sentence(),
+            self.paragraph_count
+        )
+    }
+}
+
+#[cfg(test)]
+mod structure_tests {
+    use super::*;
+
+    const DOCUMENT: &str = "Dr. Smith met J. R. Doe at 5 p.m. today.\n\
+                            They talked, e.g. about lint rules. Fine!\n\
+                            \n\
+                            \n\
+                            A second paragraph with one line that is rather long\n";
+
+    #[test]
+    fn test_sentences_with_abbreviations() {
+        assert_eq!(count_sentences("Dr. Smith met J. R. Doe. He left."), 2);
+        assert_eq!(count_sentences("Lists, e.g. this one, etc. keep going"), 1);
+        assert_eq!(count_sentences("\"Really?\" she asked. (Yes.)"), 3);
+        assert_eq!(count_sentences("  ...  "), 0);
+    }
+
+    #[test]
+    fn test_no_and_pronoun_i_end_sentences() {
+        assert_eq!(count_sentences("The answer is no. We left."), 2);
+        assert_eq!(count_sentences("See No. 5 on the list."), 1);
+        assert_eq!(count_sentences("So did I. Then we left."), 2);
+    }
+
+    #[test]
+    fn test_line_and_paragraph_statistics() {
+        let stats = StructureStatistics::from_text(DOCUMENT);
+        assert_eq!(stats.longest_line(), Some(52));
+        assert_eq!(stats.shortest_line(), Some(0));
+        assert_eq!(stats.blank_line_count, 2);
+        assert_eq!(stats.paragraph_count, 2);
+        assert_eq!(stats.sentence_count, 4);
+        assert_eq!(stats.lines_longer_than(45), [5]);
+        assert_eq!(stats.line_length_histogram(20), BTreeMap::from([(0, 2), (40, 3)]));
+    }
+
+    #[test]
+    fn test_report_and_empty_text() {
+        let empty = StructureStatistics::from_text("");
+        assert_eq!(empty.longest_line(), None);
+        assert_eq!(empty.average_words_per_sentence(), 0.0);
+        assert_eq!(
+            StructureStatistics::from_text("One two three. Four.\n\nFive").to_string(),
+            "Lines: 3 (longest 20, shortest 0, average 8.00, blank 1), \
+             Sentences: 3 (1.67 words each), Paragraphs: 2"
+        );
+    }
+}
@@ -0,0 +1,131 @@
+// ============================================
+// Kata 2 extension: Pluggable Tokenizers
+// Different definitions of a "word" for prose, code and hashtags
+// ============================================
+
+use super::TextStatistics;
+use regex::Regex;
+
+/// Splits text into the words that get counted
+pub trait Tokenizer {
+    fn tokenize<'a>(&self, text: &'a str) -> Vec<&'a str>;
+
+    fn count(&self, text: &str) -> usize {
+        self.tokenize(text).len()
+    }
+}
+
+/// Words separated by whitespace, as `from_text` counts them
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WhitespaceTokenizer;
+
+impl Tokenizer for WhitespaceTokenizer {
+    fn tokenize<'a>(&self, text: &'a str) -> Vec<&'a str> {
+        text.split_whitespace().collect()
+    }
+}
+
+/// Every non-overlapping match of a regular expression is a word
+#[derive(Debug, Clone)]
+pub struct RegexTokenizer {
+    pattern: Regex,
+}
+
+impl RegexTokenizer {
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        Ok(RegexTokenizer { pattern: Regex::new(pattern)? })
+    }
+
+    /// `#tags` made of letters, digits and underscores
+    pub fn hashtags() -> Self {
+        Self::new(r"#\w+").expect("hashtag pattern is valid")
+    }
+}
+
+impl Tokenizer for RegexTokenizer {
+    fn tokenize<'a>(&self, text: &'a str) -> Vec<&'a str> {
+        self.pattern.find_iter(text).map(|found| found.as_str()).collect()
+    }
+}
+
+/// Identifier parts: `parseHTTPResponse` and `max_line_len` each give three words
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IdentifierTokenizer;
+
+impl Tokenizer for IdentifierTokenizer {
+    fn tokenize<'a>(&self, text: &'a str) -> Vec<&'a str> {
+        text.split(|ch: char| !ch.is_alphanumeric())
+            .filter(|segment| !segment.is_empty())
+            .flat_map(split_camel_case)
+            .collect()
+    }
+}
+
+/// Split before an uppercase letter that starts a new word, keeping acronyms and digits attached
+fn split_camel_case(segment: &str) -> Vec<&str> {
+    let chars: Vec<(usize, char)> = segment.char_indices().collect();
+    let mut parts = Vec::new();
+    let mut start = 0;
+
+    for window in chars.windows(2) {
+        let [(_, previous), (index, current)] = [window[0], window[1]];
+        let next_is_lower = segment[index..]
+            .chars()
+            .nth(1)
+            .is_some_and(char::is_lowercase);
+        let lower_to_upper = !previous.is_uppercase() && current.is_uppercase();
+        let acronym_end = previous.is_uppercase() && current.is_uppercase() && next_is_lower;
+        if lower_to_upper || acronym_end {
+            parts.push(&segment[start..index]);
+            start = index;
+        }
+    }
+    parts.push(&segment[start..]);
+    parts
+}
+
+impl TextStatistics {
+    /// Create statistics from text, counting words with `tokenizer`.
+    ///
+    /// The result must not be joined with `followed_by`: whether a word continues across the
+    /// boundary depends on the tokenizer (`fooBar` then `Baz` is three identifier words), so
+    /// tokenize the joined text instead. Adding independent documents with `+` is fine.
+    pub fn from_text_with_tokenizer(text: &str, tokenizer: &dyn Tokenizer) -> Self {
+        Self {
+            word_count: tokenizer.count(text),
+            ..Self::from_text(text)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tokenizer_tests {
+    use super::*;
+
+    #[test]
+    fn test_identifier_tokenizer() {
+        let words = IdentifierTokenizer.tokenize("parseHTTPResponse(max_line_len, utf8Decoder)");
+        assert_eq!(words, ["parse", "HTTP", "Response", "max", "line", "len", "utf8", "Decoder"]);
+        let dunder = IdentifierTokenizer.tokenize("IOError __init__ x");
+        assert_eq!(dunder, ["IO", "Error", "init", "x"]);
+    }
+
+    #[test]
+    fn test_regex_tokenizer() {
+        let hashtags = RegexTokenizer::hashtags();
+        let tags = hashtags.tokenize("Loving #RustLang and #clean_code! #");
+        assert_eq!(tags, ["#RustLang", "#clean_code"]);
+        assert!(RegexTokenizer::new("(").is_err());
+    }
+
+    #[test]
+    fn test_statistics_with_selected_tokenizer() {
+        let code = "let totalCount = item_count + 1;";
+        let whitespace = TextStatistics::from_text_with_tokenizer(code, &WhitespaceTokenizer);
+        assert_eq!(whitespace, TextStatistics::from_text(code));
+
+        let identifiers = TextStatistics::from_text_with_tokenizer(code, &IdentifierTokenizer);
+        assert_eq!(identifiers.word_count, 6);
+        assert_eq!(identifiers.character_count, whitespace.character_count);
+    }
+}
@@ -0,0 +1,65 @@
+// ============================================
+// Kata 2 extension: Unicode-Aware Counting
+// Grapheme clusters and UAX #29 words instead of chars and whitespace
+// ============================================
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// How words and characters are delimited when computing statistics
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum CountingMode {
+    /// Whitespace-separated words and Unicode scalar values, as `from_text` always did
+    #[default]
+    Standard,
+    /// UAX #29 word boundaries and extended grapheme clusters
+    Unicode,
+}
+
+/// Count words by UAX #29 boundaries, skipping punctuation and whitespace segments
+pub fn count_unicode_words(text: &str) -> usize {
+    text.unicode_words().count()
+}
+
+/// Count user-perceived characters
+pub fn count_graphemes(text: &str) -> usize {
+    text.graphemes(true).count()
+}
+
+/// Count user-perceived characters that are not whitespace
+pub fn count_graphemes_excluding_spaces(text: &str) -> usize {
+    text.graphemes(true)
+        .filter(|grapheme| !grapheme.chars().all(char::is_whitespace))
+        .count()
+}
+
+#[cfg(test)]
+mod unicode_tests {
+    use super::*;
+    use crate::kata2_string_processor::TextStatistics;
+
+    #[test]
+    fn test_emoji_with_modifiers_are_single_characters() {
+        let text = "👍🏽 👨‍👩‍👧";
+        assert_eq!(count_graphemes(text), 3);
+        assert_eq!(count_graphemes_excluding_spaces(text), 2);
+        assert_eq!(text.chars().count(), 8);
+    }
+
+    #[test]
+    fn test_cjk_words_are_segmented() {
+        let stats = TextStatistics::from_text_with_mode("你好世界", CountingMode::Unicode);
+        assert_eq!(stats.word_count, 4);
+        assert_eq!(TextStatistics::from_text("你好世界").word_count, 1);
+    }
+
+    #[test]
+    fn test_standard_mode_matches_from_text() {
+        let text = "Hello, world!\r\nnaïve café";
+        let standard = TextStatistics::from_text_with_mode(text, CountingMode::Standard);
+        let unicode = TextStatistics::from_text_with_mode(text, CountingMode::Unicode);
+        assert_eq!(standard.to_string(), TextStatistics::from_text(text).to_string());
+        assert_eq!(unicode.word_count, 4);
+        assert_eq!(unicode.line_count, 2);
+        assert_eq!(unicode.character_count, 24);
+    }
+}
@@ -0,0 +1,318 @@
+// ============================================
+// Kata 2 extension: wc Command
+// `wc`-style line, word and character counts for files or stdin
+// ============================================
+
+use super::TextStatistics;
+use super::encoding::TextReport;
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Read, Write};
+
+const USAGE: &str = "usage: wc [-l] [-w] [-m] [--json] [FILE]...\n  \
+                     -l counts line breaks as wc does, and also a lone \\r";
+
+#[derive(Debug, PartialEq)]
+pub enum WcError {
+    UnknownOption(String),
+}
+
+impl fmt::Display for WcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WcError::UnknownOption(option) => write!(f, "unknown option '{}'", option),
+        }
+    }
+}
+
+impl Error for WcError {}
+
+/// Parsed command line; no column flags means all columns
+#[derive(Debug, PartialEq)]
+pub struct WcOptions {
+    pub lines: bool,
+    pub words: bool,
+    pub characters: bool,
+    pub json: bool,
+    /// Paths to count, where `-` (or an empty list) means stdin
+    pub files: Vec<String>,
+}
+
+impl WcOptions {
+    pub fn parse(args: &[String]) -> Result<Self, WcError> {
+        let mut options = WcOptions {
+            lines: false,
+            words: false,
+            characters: false,
+            json: false,
+            files: Vec::new(),
+        };
+
+        for arg in args {
+            match arg.as_str() {
+                "--json" => options.json = true,
+                "-" => options.files.push(arg.clone()),
+                flags if flags.starts_with('-') => {
+                    for flag in flags.chars().skip(1) {
+                        match flag {
+                            'l' => options.lines = true,
+                            'w' => options.words = true,
+                            'm' => options.characters = true,
+                            _ => return Err(WcError::UnknownOption(arg.clone())),
+                        }
+                    }
+                }
+                _ => options.files.push(arg.clone()),
+            }
+        }
+
+        if !(options.lines || options.words || options.characters) {
+            options.lines = true;
+            options.words = true;
+            options.characters = true;
+        }
+        Ok(options)
+    }
+
+    /// Selected columns in `wc` order: lines, words, characters
+    fn columns(&self, stats: &TextStatistics) -> Vec<(&'static str, usize)> {
+        let mut columns = Vec::with_capacity(3);
+        if self.lines {
+            columns.push(("lines", stats.line_break_count));
+        }
+        if self.words {
+            columns.push(("words", stats.word_count));
+        }
+        if self.characters {
+            columns.push(("characters", stats.character_count));
+        }
+        columns
+    }
+}
+
+/// Run the command, returning the process exit code
+pub fn run(
+    args: &[String],
+    stdin: impl Read,
+    mut stdout: impl Write,
+    mut stderr: impl Write,
+) -> io::Result<i32> {
+    let options = match WcOptions::parse(args) {
+        Ok(options) => options,
+        Err(error) => {
+            writeln!(stderr, "wc: {}\n{}", error, USAGE)?;
+            return Ok(2);
+        }
+    };
+
+    let mut stdin = Some(stdin);
+    let names = if options.files.is_empty() {
+        vec!["-".to_string()]
+    } else {
+        options.files.clone()
+    };
+
+    let mut rows = Vec::with_capacity(names.len());
+    let mut exit_code = 0;
+    for name in names {
+        let counted = if name == "-" {
+            match stdin.take() {
+                Some(reader) => count(reader),
+                None => Ok(TextStatistics::default()),
+            }
+        } else {
+            File::open(&name).and_then(count)
+        };
+        match counted {
+            Ok(stats) => rows.push((name, stats)),
+            Err(error) => {
+                writeln!(stderr, "wc: {}: {}", name, error)?;
+                exit_code = 1;
+            }
+        }
+    }
+
+    if options.json {
+        write_json(&options, &rows, &mut stdout)?;
+    } else {
+        write_table(&options, &rows, &mut stdout)?;
+    }
+    Ok(exit_code)
+}
+
+/// Count an input as it streams in, decoded like `TextReport` so non-UTF-8 text is accepted
+fn count(reader: impl Read) -> io::Result<TextStatistics> {
+    TextReport::from_reader(reader).map(|report| report.statistics)
+}
+
+fn write_table(
+    options: &WcOptions,
+    rows: &[(String, TextStatistics)],
+    out: &mut impl Write,
+) -> io::Result<()> {
+    let total: TextStatistics = rows.iter().map(|(_, stats)| stats).sum();
+    let width = options
+        .columns(&total)
+        .iter()
+        .map(|(_, count)| count.to_string().len())
+        .max()
+        .unwrap_or(1);
+
+    let mut write_row = |name: &str, stats: &TextStatistics| -> io::Result<()> {
+        let counts: Vec<String> = options
+            .columns(stats)
+            .iter()
+            .map(|(_, count)| format!("{:>width$}", count, width = width))
+            .collect();
+        let mut row = counts.join(" ");
+        if name != "-" {
+            row.push(' ');
+            row.push_str(name);
+        }
+        writeln!(out, "{}", row)
+    };
+
+    for (name, stats) in rows {
+        write_row(name, stats)?;
+    }
+    if rows.len() > 1 {
+        write_row("total", &total)?;
+    }
+    Ok(())
+}
+
+fn write_json(
+    options: &WcOptions,
+    rows: &[(String, TextStatistics)],
+    out: &mut impl Write,
+) -> io::Result<()> {
+    let object = |stats: &TextStatistics| -> String {
+        let fields: Vec<String> = options
+            .columns(stats)
+            .iter()
+            .map(|(key, count)| format!("\"{}\":{}", key, count))
+            .collect();
+        fields.join(",")
+    };
+
+    let total: TextStatistics = rows.iter().map(|(_, stats)| stats).sum();
+    let files: Vec<String> = rows
+        .iter()
+        .map(|(name, stats)| format!("{{\"name\":{},{}}}", json_string(name), object(stats)))
+        .collect();
+    writeln!(
+        out,
+        "{{\"files\":[{}],\"total\":{{{}}}}}",
+        files.join(","),
+        object(&total)
+    )
+}
+
+/// Quote a string for JSON, escaping quotes, backslashes and control characters
+fn json_string(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            ch if ch.is_control() => quoted.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => quoted.push(ch),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod wc_tests {
+    use super::*;
+    use crate::kata2_string_processor::streaming::CHUNK_SIZE;
+    use std::process;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    fn run_with(list: &[&str], stdin: &str) -> (i32, String, String) {
+        let (mut stdout, mut stderr) = (Vec::new(), Vec::new());
+        let code = run(&args(list), stdin.as_bytes(), &mut stdout, &mut stderr).unwrap();
+        (
+            code,
+            String::from_utf8(stdout).unwrap(),
+            String::from_utf8(stderr).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_option_parsing() {
+        let options = WcOptions::parse(&args(&["-lw", "--json", "a.txt", "-"])).unwrap();
+        assert!(options.lines && options.words && !options.characters && options.json);
+        assert_eq!(options.files, ["a.txt", "-"]);
+
+        let defaults = WcOptions::parse(&[]).unwrap();
+        assert!(defaults.lines && defaults.words && defaults.characters);
+        assert_eq!(
+            WcOptions::parse(&args(&["-x"])),
+            Err(WcError::UnknownOption("-x".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_files_with_total_and_missing_file() {
+        let directory = std::env::temp_dir().join(format!("kata2_wc_tests_{}", process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let first = directory.join("first.txt");
+        let second = directory.join("second.txt");
+        std::fs::write(&first, "one two\nthree\n").unwrap();
+        std::fs::write(&second, "four").unwrap();
+        let (first, second) = (first.to_str().unwrap(), second.to_str().unwrap());
+
+        let (code, stdout, stderr) = run_with(&["-lw", first, "missing.txt", second], "");
+        std::fs::remove_dir_all(&directory).unwrap();
+        assert_eq!(code, 1);
+        assert!(stderr.starts_with("wc: missing.txt: "));
+        assert_eq!(stdout, format!("2 3 {}\n0 1 {}\n2 4 total\n", first, second));
+    }
+
+    #[test]
+    fn test_stdin_as_json() {
+        let (code, stdout, _) = run_with(&["--json", "-m", "-"], "héllo\n");
+        assert_eq!(code, 0);
+        assert_eq!(
+            stdout,
+            "{\"files\":[{\"name\":\"-\",\"characters\":6}],\"total\":{\"characters\":6}}\n"
+        );
+        assert_eq!(json_string("a\"b\\c\u{1}"), "\"a\\\"b\\\\c\\u0001\"");
+    }
+
+    #[test]
+    fn test_streams_input_larger_than_a_chunk() {
+        let spaces = CHUNK_SIZE * 3;
+        let latin1 = io::repeat(b' ').take(spaces as u64).chain(&b"caf\xE9\n"[..]);
+        let mut stdout = Vec::new();
+        run(&args(&["--json"]), latin1, &mut stdout, io::sink()).unwrap();
+        let counts = format!("\"lines\":1,\"words\":1,\"characters\":{}", spaces + 5);
+        assert_eq!(
+            String::from_utf8(stdout).unwrap(),
+            format!("{{\"files\":[{{\"name\":\"-\",{0}}}],\"total\":{{{0}}}}}\n", counts)
+        );
+
+        let (_, stdout, _) = run_with(&["-m"], &"é".repeat(CHUNK_SIZE));
+        assert_eq!(stdout, format!("{}\n", CHUNK_SIZE));
+    }
+
+    #[test]
+    fn test_latin1_and_cr_input() {
+        let mut stdout = Vec::new();
+        let code = run(&args(&["-lm"]), &b"caf\xE9\n"[..], &mut stdout, io::sink()).unwrap();
+        assert_eq!(code, 0);
+        assert_eq!(String::from_utf8(stdout).unwrap(), "1 5\n");
+
+        let (_, stdout, _) = run_with(&["-l"], "a\rb\rc");
+        assert_eq!(stdout, "2\n");
+    }
+}
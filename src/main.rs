@@ -1,5 +1,11 @@
+// Benchmarks use the nightly-only `test` crate
+#![cfg_attr(test, feature(test))]
+
+// Katas expose library-style APIs that are exercised by their tests rather than by `main`
+#[allow(dead_code)]
 mod kata1_temperature_converter;
-//mod kata2_string_processor;
+#[allow(dead_code)]
+mod kata2_string_processor;
 //mod kata3_simple_calculator;
 //mod kata4_user_validation;
 //mod kata5_shape_area_calculator;
@@ -7,6 +13,21 @@ mod kata1_temperature_converter;
 //mod kata7_mini_todo_application;
 //mod kata8_simple_parser;
 
+use kata2_string_processor::wc;
+use std::io;
+use std::process;
+
 fn main() {
-    println!("Hello, world!");
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.split_first() {
+        Some((command, rest)) if command == "wc" => {
+            let result = wc::run(rest, io::stdin().lock(), io::stdout(), io::stderr());
+            let code = result.unwrap_or_else(|error| {
+                eprintln!("wc: {}", error);
+                1
+            });
+            process::exit(code);
+        }
+        _ => println!("Hello, world!"),
+    }
 }